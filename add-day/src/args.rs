@@ -0,0 +1,145 @@
+use crate::templates::DEFAULT_TEMPLATE;
+use aoc_utils::DEFAULT_YEAR;
+
+pub const USAGE: &str = "Usage: add-day --day <day> [--year <year>] [--template <name>]
+               [--title <title>] [--puzzle <html file>] [--fetch] [--force] [--dry-run]
+
+Creates the crate for the given day (1 to 25) in the current directory, or in
+year<year>/day<day> for other years than 2021, as a library with a thin binary running it.
+It is created from one of the templates grid, lines or sections (lines by default),
+library-style is accepted as another name for lines.
+Templates in the directory named by AOC_TEMPLATES_DIR or in ~/.config/aoc/templates
+take precedence over the built-in ones.
+--puzzle reads the title, example input and example answers from a saved puzzle page.
+--fetch downloads the input using the session token from AOC_SESSION or ~/.config/aoc/session.
+--force overwrites the files of an existing day, except for its input.
+--dry-run only prints the files that would be created.";
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Args {
+    pub day: u8,
+    pub year: u16,
+    pub template: String,
+    /// Title of the puzzle, `None` if it wasn't given.
+    pub title: Option<String>,
+    /// Saved HTML of the puzzle description.
+    pub puzzle: Option<String>,
+    /// Download the input instead of creating an empty input file.
+    pub fetch: bool,
+    pub force: bool,
+    pub dry_run: bool,
+}
+
+/// Parses the command line arguments, without the program name.
+pub fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter();
+    let mut day = None;
+    let mut year = DEFAULT_YEAR;
+    let mut template = DEFAULT_TEMPLATE.to_string();
+    let mut title = None;
+    let mut puzzle = None;
+    let mut fetch = false;
+    let mut force = false;
+    let mut dry_run = false;
+
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_day(value()?)?),
+            "--year" | "-y" => year = parse_year(value()?)?,
+            "--template" | "-t" => template = value()?.clone(),
+            "--title" => title = Some(value()?.clone()),
+            "--puzzle" | "-p" => puzzle = Some(value()?.clone()),
+            "--fetch" => fetch = true,
+            "--force" | "-f" => force = true,
+            "--dry-run" | "-n" => dry_run = true,
+            _ => return Err(format!("Unexpected argument \"{}\"", arg)),
+        }
+    }
+
+    Ok(Args {
+        day: day.ok_or("Missing --day")?,
+        year,
+        template,
+        title,
+        puzzle,
+        fetch,
+        force,
+        dry_run,
+    })
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Invalid day \"{}\", expected a number from 1 to 25",
+            s
+        )),
+    }
+}
+
+fn parse_year(s: &str) -> Result<u16, String> {
+    match s.parse::<u16>() {
+        // The first Advent of Code took place in 2015
+        Ok(year) if (2015..10000).contains(&year) => Ok(year),
+        _ => Err(format!("Invalid year \"{}\"", s)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(&args("--day 7")),
+            Ok(Args {
+                day: 7,
+                year: 2021,
+                template: "lines".to_string(),
+                title: None,
+                puzzle: None,
+                fetch: false,
+                force: false,
+                dry_run: false
+            })
+        );
+        assert_eq!(
+            parse_args(&args(
+                "--dry-run --year 2021 -d 25 --force -t grid --title Cucumber --puzzle day25.html --fetch"
+            )),
+            Ok(Args {
+                day: 25,
+                year: 2021,
+                template: "grid".to_string(),
+                title: Some("Cucumber".to_string()),
+                puzzle: Some("day25.html".to_string()),
+                fetch: true,
+                force: true,
+                dry_run: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_args() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("--day")).is_err());
+        assert!(parse_args(&args("--day 0")).is_err());
+        assert!(parse_args(&args("--day 26")).is_err());
+        assert!(parse_args(&args("--day seven")).is_err());
+        assert!(parse_args(&args("--day 7 --year 2014")).is_err());
+        assert!(parse_args(&args("--day 7 --year 20222")).is_err());
+        assert!(parse_args(&args("--day 7 8")).is_err());
+        assert!(parse_args(&args("--day 7 --template")).is_err());
+    }
+}
@@ -1,108 +1,328 @@
+mod args;
+mod puzzle;
+mod templates;
+
+use aoc_utils::fetch::Fetcher;
+use aoc_utils::{day_path, input_file_name, DEFAULT_YEAR};
+use args::Args;
+use puzzle::Puzzle;
 use std::fs;
-use std::io::{self, Write};
-use std::path::Path;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use templates::Variables;
 
-// This is a utility that asks the user for a input number
-// and then creates a new crate for the advent of code challange of that day.
+// This is a utility that creates a new crate for the advent of code challange of a day,
+// e.g. `cargo run -p add-day -- --day 7`.
 
 fn main() {
-    let day = get_day();
-    let day_dir = create_day_dir(day);
-    create_cargo_toml(day, &day_dir);
-    create_src(day, &day_dir);
-    create_input_file(day, &day_dir);
-}
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let args = match args::parse_args(&args) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, args::USAGE);
+            exit(2);
+        }
+    };
 
-fn get_day() -> u8 {
-    print!("Please enter the day you want to create a crate for: ");
-    io::stdout().flush().unwrap();
+    let root = Path::new(".");
+    match scaffold(root, &args, &templates::search_dirs()) {
+        Ok(files) => {
+            println!(
+                "{}",
+                if args.dry_run {
+                    "Would create:"
+                } else {
+                    "Created:"
+                }
+            );
+            for file in files {
+                println!("  {}", file.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
 
-    let mut day = String::new();
-    io::stdin()
-        .read_line(&mut day)
-        .expect("Failed to read line");
-    day.trim().parse::<u8>().expect("Please enter a number!")
+    match add_workspace_member(root, args.year, args.dry_run) {
+        Ok(Some(member)) => println!(
+            "{} {} to the workspace members",
+            if args.dry_run { "Would add" } else { "Added" },
+            member
+        ),
+        Ok(None) => (),
+        Err(e) => {
+            eprintln!("{}", e);
+            exit(1);
+        }
+    }
 }
 
-fn create_day_dir(day: u8) -> PathBuf {
-    let day_directory_str = format!("day{}", day);
-    let day_directory = PathBuf::from(&day_directory_str);
-    fs::create_dir(&day_directory).expect("Could not create day directory");
-
-    day_directory
+struct NewFile {
+    path: PathBuf,
+    content: String,
 }
 
-fn create_cargo_toml(day: u8, day_dir: &Path) {
-    let cargo_toml_path = day_dir.join("Cargo.toml");
-    let cargo_toml_str = format!(
-        r#"[package]
-name = "day{:02}"
-version = "0.1.0"
-edition = "2021"
-
-[dependencies]
-aoc-utils = {{ path = "../aoc-utils" }}
-"#,
-        day
-    );
-    fs::write(&cargo_toml_path, cargo_toml_str).expect("Could not write Cargo.toml");
+/// Creates the crate of the day inside `root` from the template and returns the paths of the created files.
+/// An existing day is only overwritten with `--force`, and never its input.
+fn scaffold(root: &Path, args: &Args, template_dirs: &[PathBuf]) -> Result<Vec<PathBuf>, String> {
+    let day_dir = day_path(args.year, args.day);
+    if root.join(&day_dir).exists() && !args.force {
+        return Err(format!(
+            "{} already exists, use --force to overwrite it",
+            day_dir.display()
+        ));
+    }
+
+    let mut files = day_files(args, &day_dir, template_dirs)?
+        .into_iter()
+        .filter(|file| !(is_input(file) && root.join(&file.path).exists()))
+        .collect::<Vec<_>>();
+
+    if args.fetch && !args.dry_run {
+        if let Some(input) = files.iter_mut().find(|file| is_input(file)) {
+            input.content = Fetcher::from_env()
+                .and_then(|mut fetcher| fetcher.fetch(args.year, args.day))
+                .map_err(|e| e.to_string())?;
+        }
+    }
+
+    if !args.dry_run {
+        for file in files.iter() {
+            let path = root.join(&file.path);
+            let dir = path.parent().unwrap();
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+            fs::write(&path, &file.content)
+                .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+        }
+    }
+
+    Ok(files.into_iter().map(|file| file.path).collect())
 }
 
-fn create_src(day: u8, day_dir: &Path) {
-    let src_dir = &day_dir.join("src");
-    fs::create_dir(src_dir).expect("Could not create src directory");
+/// Adds the days of the year to the members of the workspace in `root`, unless they already are.
+/// Returns the added member pattern. Days of the default year are always members.
+fn add_workspace_member(root: &Path, year: u16, dry_run: bool) -> Result<Option<String>, String> {
+    if year == DEFAULT_YEAR {
+        return Ok(None);
+    }
 
-    let main_rs_path = src_dir.join("main.rs");
-    let main_rs_str = format!(
-        r#"use aoc_utils::PuzzleInput;
-const DAY: u8 = {day};
+    let path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    let member = format!("year{}/day*", year);
+    if manifest.contains(&format!("\"{}\"", member)) {
+        return Ok(None);
+    }
 
-fn main() {{
-    let input = PuzzleInput::get_input(DAY);
-    println!("A: {{}}", solve_a(&input));
-    println!("B: {{}}", solve_b(&input));
-}}
+    let members_end = manifest
+        .find("members = [")
+        .and_then(|start| Some(start + manifest[start..].find(']')?))
+        .ok_or_else(|| format!("No workspace members found in {}", path.display()))?;
+    let (before, after) = manifest.split_at(members_end);
+    let updated = format!(
+        "{},\n    \"{}\",\n{}",
+        before.trim_end().trim_end_matches(','),
+        member,
+        after
+    );
 
-fn solve_a(input: &PuzzleInput) -> usize {{
-    input.lines().len()
-}}
+    if !dry_run {
+        fs::write(&path, updated)
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    }
+    Ok(Some(member))
+}
 
-fn solve_b(input: &PuzzleInput) -> usize {{
-    input.lines().len()
-}}
+fn is_input(file: &NewFile) -> bool {
+    file.path.extension().is_some_and(|ext| ext == "txt")
+}
+
+fn day_files(
+    args: &Args,
+    day_dir: &Path,
+    template_dirs: &[PathBuf],
+) -> Result<Vec<NewFile>, String> {
+    let puzzle = match &args.puzzle {
+        Some(path) => puzzle::parse_html(
+            &fs::read_to_string(path).map_err(|e| format!("Could not read {}: {}", path, e))?,
+        ),
+        None => Puzzle::default(),
+    };
+    let variables = Variables {
+        day: args.day,
+        year: args.year,
+        title: args
+            .title
+            .clone()
+            .or(puzzle.title)
+            .unwrap_or_else(|| format!("Day {}", args.day)),
+        example: puzzle.example,
+        answer_a: puzzle.answer_a,
+        answer_b: puzzle.answer_b,
+    };
+    let template_dir = templates::find(&args.template, template_dirs)?;
+
+    let mut files = templates::render_dir(&template_dir, &variables)?
+        .into_iter()
+        .map(|(path, content)| NewFile {
+            path: day_dir.join(path),
+            content,
+        })
+        .collect::<Vec<_>>();
+    files.push(NewFile {
+        path: day_dir.join(input_file_name(args.day)),
+        content: String::new(),
+    });
+    Ok(files)
+}
 
 #[cfg(test)]
-mod tests {{
+mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "";
+    fn args(day: u8, force: bool, dry_run: bool) -> Args {
+        Args {
+            day,
+            year: DEFAULT_YEAR,
+            template: templates::DEFAULT_TEMPLATE.to_string(),
+            title: None,
+            puzzle: None,
+            fetch: false,
+            force,
+            dry_run,
+        }
+    }
+
+    fn builtin_templates() -> Vec<PathBuf> {
+        vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")]
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("add-day-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
 
     #[test]
-    fn test_no_panic() {{
-        let input = PuzzleInput::get_input(DAY);
-        solve_a(&input);
-        solve_b(&input);
-    }}
+    fn test_dry_run_creates_nothing() {
+        let root = temp_root("dry-run");
+        let files = scaffold(&root, &args(7, false, true), &builtin_templates()).unwrap();
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("day07/Cargo.toml"),
+                PathBuf::from("day07/src/lib.rs"),
+                PathBuf::from("day07/src/main.rs"),
+                PathBuf::from("day07/input_day07.txt")
+            ]
+        );
+        assert!(!root.join("day07").exists());
+        fs::remove_dir_all(root).unwrap();
+    }
 
     #[test]
-    fn test_solve_a() {{
-        assert_eq!(solve_a(&PuzzleInput::new(TEST_INPUT)), 0);
-    }}
+    fn test_refuses_to_overwrite() {
+        let root = temp_root("overwrite");
+        scaffold(&root, &args(7, false, false), &builtin_templates()).unwrap();
+        assert!(root.join("day07/src/lib.rs").exists());
+
+        fs::write(root.join("day07/src/lib.rs"), "solution").unwrap();
+        fs::write(root.join("day07/input_day07.txt"), "input").unwrap();
+        assert!(scaffold(&root, &args(7, false, false), &builtin_templates()).is_err());
+        assert_eq!(
+            fs::read_to_string(root.join("day07/src/lib.rs")).unwrap(),
+            "solution"
+        );
+
+        let files = scaffold(&root, &args(7, true, false), &builtin_templates()).unwrap();
+        assert_eq!(files.len(), 3);
+        assert_ne!(
+            fs::read_to_string(root.join("day07/src/lib.rs")).unwrap(),
+            "solution"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("day07/input_day07.txt")).unwrap(),
+            "input"
+        );
+        fs::remove_dir_all(root).unwrap();
+    }
 
     #[test]
-    fn test_solve_b() {{
-        assert_eq!(solve_b(&PuzzleInput::new(TEST_INPUT)), 0);
-    }}
-}}
-"#,
-        day = day
-    );
-    fs::write(&main_rs_path, main_rs_str).expect("Could not write main.rs");
-}
+    fn test_add_workspace_member() {
+        let root = temp_root("workspace");
+        let manifest = "[workspace]\n\nmembers = [\n    \"aoc-utils\",\n    \"day*\"\n]\n";
+        fs::write(root.join("Cargo.toml"), manifest).unwrap();
+
+        assert_eq!(add_workspace_member(&root, DEFAULT_YEAR, false), Ok(None));
+        assert_eq!(
+            add_workspace_member(&root, 2022, true),
+            Ok(Some("year2022/day*".to_string()))
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            manifest
+        );
+
+        add_workspace_member(&root, 2022, false).unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\n\nmembers = [\n    \"aoc-utils\",\n    \"day*\",\n    \"year2022/day*\",\n]\n"
+        );
+        assert_eq!(add_workspace_member(&root, 2022, false), Ok(None));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_puzzle_example_in_tests() {
+        let root = temp_root("puzzle");
+        let puzzle = root.join("day07.html");
+        fs::write(
+            &puzzle,
+            "<article><h2>--- Day 7: The Treachery of Whales ---</h2>\
+             <pre><code>16,1,2</code></pre><code><em>37</em></code></article>\
+             <article><code><em>168</em></code></article>",
+        )
+        .unwrap();
+        let args = Args {
+            puzzle: Some(puzzle.to_string_lossy().into_owned()),
+            ..args(7, false, false)
+        };
+        scaffold(&root, &args, &builtin_templates()).unwrap();
+
+        let lib_rs = fs::read_to_string(root.join("day07/src/lib.rs")).unwrap();
+        assert!(lib_rs.contains("//! Day 7: The Treachery of Whales"));
+        assert!(lib_rs.contains("const TEST_INPUT: &str = \"16,1,2\";"));
+        assert!(lib_rs.contains("assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 37);"));
+        assert!(lib_rs.contains("assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 168);"));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_generated_day_finds_input() {
+        let root = temp_root("input");
+        for year in [DEFAULT_YEAR, 2022] {
+            let args = Args {
+                year,
+                ..args(7, false, false)
+            };
+            scaffold(&root, &args, &builtin_templates()).unwrap();
 
-fn create_input_file(day: u8, day_dir: &Path) {
-    let name = format!("input_day{:02}.txt", day);
-    let input_file_path = day_dir.join(name);
-    fs::File::create(input_file_path).expect("Could not create input file");
+            let package_dir = root.join(day_path(year, 7));
+            for working_dir in [&root, &package_dir] {
+                assert!(
+                    aoc_utils::input_search_paths(year, 7)
+                        .iter()
+                        .any(|path| working_dir.join(path).is_file()),
+                    "Input of {} not found from {}",
+                    year,
+                    working_dir.display()
+                );
+            }
+        }
+        fs::remove_dir_all(root).unwrap();
+    }
 }
@@ -0,0 +1,135 @@
+/// Title, example input and example answers found in a saved puzzle description.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub title: Option<String>,
+    pub example: Option<String>,
+    pub answer_a: Option<String>,
+    pub answer_b: Option<String>,
+}
+
+/// Extracts the puzzle from the HTML of a puzzle page. The first `<pre><code>` block of part one
+/// is used as example, and the last emphasised code (`<code><em>`) of each part as its answer.
+/// Answers to the real input, shown below the parts of solved puzzles, are ignored.
+pub fn parse_html(html: &str) -> Puzzle {
+    let articles = elements(html, "<article", "</article>");
+    let part_a = articles.first().copied().unwrap_or_default();
+    let part_b = articles.get(1).copied().unwrap_or_default();
+
+    Puzzle {
+        title: elements(part_a, "<h2", "</h2>")
+            .first()
+            .map(|h2| text(h2))
+            .map(|title| parse_title(&title)),
+        example: elements(part_a, "<pre><code>", "</code></pre>")
+            .first()
+            .map(|code| text(code).trim_end_matches('\n').to_string()),
+        answer_a: last_answer(part_a),
+        answer_b: last_answer(part_b),
+    }
+}
+
+/// `--- Day 1: Sonar Sweep ---` becomes `Sonar Sweep`.
+fn parse_title(heading: &str) -> String {
+    let heading = heading.trim().trim_matches('-').trim();
+    match heading.split_once(": ") {
+        Some((_, title)) => title.to_string(),
+        None => heading.to_string(),
+    }
+}
+
+fn last_answer(article: &str) -> Option<String> {
+    let mut answers = elements(article, "<code><em>", "</em></code>");
+    answers.extend(elements(article, "<em><code>", "</code></em>"));
+    // Restore the order in the article, the elements are slices of it
+    answers.sort_by_key(|answer| answer.as_ptr());
+    answers.last().map(|answer| text(answer))
+}
+
+/// Contents of all elements between `start` and `end`, excluding the remainder of the start tag.
+fn elements<'a>(html: &'a str, start: &str, end: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+    while let Some(begin) = rest.find(start) {
+        rest = &rest[begin + start.len()..];
+        if !start.ends_with('>') {
+            match rest.find('>') {
+                Some(tag_end) => rest = &rest[tag_end + 1..],
+                None => break,
+            }
+        }
+        match rest.find(end) {
+            Some(stop) => {
+                found.push(&rest[..stop]);
+                rest = &rest[stop + end.len()..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+/// Removes all tags and decodes the entities used in the puzzle descriptions.
+fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE_HTML: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Sonar Sweep ---</h2><p>For example, suppose you had the following report:</p>
+<pre><code>199
+200
+<em>208</em>
+</code></pre>
+<p>In this example, there are <code><em>7</em></code> measurements that are larger than the previous one.</p>
+<p>How many measurements are larger than the previous measurement?</p>
+</article>
+<p>Your puzzle answer was <code>1383</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>A &lt;- B &amp;&amp; C</code></pre>
+<p>In this example, there are <em>5</em> sums, <code><em>-1</em></code> or <em><code>5</code></em> of them are larger.</p>
+</article>
+<p>Your puzzle answer was <code>1418</code>.</p>
+</main>"#;
+
+    #[test]
+    fn test_parse_html() {
+        assert_eq!(
+            parse_html(PUZZLE_HTML),
+            Puzzle {
+                title: Some("Sonar Sweep".to_string()),
+                example: Some("199\n200\n208".to_string()),
+                answer_a: Some("7".to_string()),
+                answer_b: Some("5".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_html_without_puzzle() {
+        assert_eq!(parse_html("<html></html>"), Puzzle::default());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(text("A &lt;- <em>B</em> &amp;&amp; C"), "A <- B && C");
+        assert_eq!(text("&amp;lt;"), "&lt;");
+    }
+}
@@ -0,0 +1,304 @@
+use aoc_utils::{day_path, package_name};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming a directory with templates that take precedence over the built-in ones.
+pub const TEMPLATES_DIR_ENV: &str = "AOC_TEMPLATES_DIR";
+
+pub const DEFAULT_TEMPLATE: &str = "lines";
+
+/// Former template names with the template that replaced them, every template now creates a library.
+const ALIASES: &[(&str, &str)] = &[("library-style", "lines")];
+
+/// Suffix of template files, it is removed from the names of the created files.
+const TEMPLATE_SUFFIX: &str = ".tmpl";
+
+/// Values of the `{{name}}` variables that are replaced in the templates.
+pub struct Variables {
+    pub day: u8,
+    pub year: u16,
+    pub title: String,
+    /// Example input of the puzzle, an empty string is used if it is unknown.
+    pub example: Option<String>,
+    /// Answers to the example, 0 is used if they are unknown.
+    /// Text answers are compared as strings, as the templates return numbers.
+    pub answer_a: Option<String>,
+    pub answer_b: Option<String>,
+}
+
+impl Variables {
+    fn get(&self, name: &str) -> Option<String> {
+        match name {
+            "day" => Some(self.day.to_string()),
+            "day_padded" => Some(format!("{:02}", self.day)),
+            "package" => Some(package_name(self.year, self.day)),
+            "crate" => Some(package_name(self.year, self.day).replace('-', "_")),
+            // aoc-utils relative to the day directory
+            "utils_path" => Some(
+                day_path(self.year, self.day)
+                    .components()
+                    .map(|_| "..")
+                    .chain(["aoc-utils"])
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            ),
+            "year" => Some(self.year.to_string()),
+            "title" => Some(self.title.clone()),
+            "test_input" => Some(format!("{:?}", self.example.as_deref().unwrap_or_default())),
+            "answer_a" => Some(answer_literal(self.answer_a.as_deref())),
+            "answer_b" => Some(answer_literal(self.answer_b.as_deref())),
+            "as_text_a" => Some(as_text(self.answer_a.as_deref())),
+            "as_text_b" => Some(as_text(self.answer_b.as_deref())),
+            _ => None,
+        }
+    }
+}
+
+/// Numeric answers are used as they are, anything else as string literal.
+fn answer_literal(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) if is_number(answer) => answer.to_string(),
+        Some(answer) => format!("{:?}", answer),
+        None => "0".to_string(),
+    }
+}
+
+fn is_number(answer: &str) -> bool {
+    answer.parse::<i64>().is_ok()
+}
+
+/// Text answers are compared with the answer converted to a string, so that the assertion
+/// compiles with the numeric return type of the templates.
+fn as_text(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) if !is_number(answer) => ".to_string()".to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Directories that are searched for templates, in order of precedence:
+/// the directory named by `AOC_TEMPLATES_DIR`, ~/.config/aoc/templates and the built-in templates.
+pub fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![];
+    if let Some(dir) = std::env::var_os(TEMPLATES_DIR_ENV) {
+        dirs.push(PathBuf::from(dir));
+    }
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(Path::new(&home).join(".config/aoc/templates"));
+    }
+    dirs.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("templates"));
+    dirs
+}
+
+/// Finds the template with the given name in the first directory that contains it.
+/// Aliases are only resolved if no directory contains a template with the name itself.
+pub fn find(name: &str, dirs: &[PathBuf]) -> Result<PathBuf, String> {
+    let find_in_dirs = |name: &str| {
+        dirs.iter()
+            .map(|dir| dir.join(name))
+            .find(|dir| dir.is_dir())
+    };
+    find_in_dirs(name)
+        .or_else(|| {
+            ALIASES
+                .iter()
+                .find(|&&(alias, _)| alias == name)
+                .and_then(|&(_, template)| find_in_dirs(template))
+        })
+        .ok_or_else(|| {
+            format!(
+                "Unknown template \"{}\", available templates: {}",
+                name,
+                available(dirs).join(", ")
+            )
+        })
+}
+
+fn available(dirs: &[PathBuf]) -> Vec<String> {
+    let mut names = dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Renders all files of a template directory and returns them with their path
+/// relative to the day directory.
+pub fn render_dir(
+    template_dir: &Path,
+    variables: &Variables,
+) -> Result<Vec<(PathBuf, String)>, String> {
+    let mut files = vec![];
+    collect_files(template_dir, Path::new(""), variables, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(
+    dir: &Path,
+    relative: &Path,
+    variables: &Variables,
+    files: &mut Vec<(PathBuf, String)>,
+) -> Result<(), String> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Could not read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Could not read {}: {}", dir.display(), e))?;
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+
+        if path.is_dir() {
+            collect_files(&path, &relative.join(&name), variables, files)?;
+        } else if let Some(name) = name.strip_suffix(TEMPLATE_SUFFIX) {
+            let template = fs::read_to_string(&path)
+                .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+            files.push((relative.join(name), render(&template, variables)));
+        }
+    }
+    Ok(())
+}
+
+/// Replaces every `{{name}}` of a known variable, anything else is kept as it is.
+pub fn render(template: &str, variables: &Variables) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        match after
+            .find("}}")
+            .and_then(|end| Some((end, variables.get(after[..end].trim())?)))
+        {
+            Some((end, value)) => {
+                result.push_str(&value);
+                rest = &after[end + 2..];
+            }
+            None => {
+                result.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables() -> Variables {
+        Variables {
+            day: 7,
+            year: 2021,
+            title: "The Treachery of Whales".to_string(),
+            example: None,
+            answer_a: None,
+            answer_b: None,
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "{{crate}}::Day{{day_padded}} ({{ day }}, {{year}}): {{title}}",
+                &variables()
+            ),
+            "day07::Day07 (7, 2021): The Treachery of Whales"
+        );
+        let next_year = Variables {
+            year: 2022,
+            ..variables()
+        };
+        assert_eq!(
+            render("{{package}} {{crate}} {{utils_path}}", &next_year),
+            "year2022-day07 year2022_day07 ../../aoc-utils"
+        );
+
+        let template = "{{test_input}} {{answer_a}} {{answer_b}}";
+        assert_eq!(render(template, &variables()), "\"\" 0 0");
+        let with_example = Variables {
+            example: Some("16,1,2\n\"x\"".to_string()),
+            answer_a: Some("37".to_string()),
+            answer_b: Some("abc".to_string()),
+            ..variables()
+        };
+        assert_eq!(
+            render(template, &with_example),
+            "\"16,1,2\\n\\\"x\\\"\" 37 \"abc\""
+        );
+        assert_eq!(
+            render("a{{as_text_a}} b{{as_text_b}}", &with_example),
+            "a b.to_string()"
+        );
+        assert_eq!(
+            render("format!(\"{{}}\", {{unknown}}) {{", &variables()),
+            "format!(\"{{}}\", {{unknown}}) {{"
+        );
+    }
+
+    #[test]
+    fn test_builtin_templates() {
+        let dirs = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("templates")];
+        assert_eq!(available(&dirs), vec!["grid", "lines", "sections"]);
+
+        let files = render_dir(&find("lines", &dirs).unwrap(), &variables()).unwrap();
+        let paths = files
+            .iter()
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("Cargo.toml"),
+                PathBuf::from("src/lib.rs"),
+                PathBuf::from("src/main.rs")
+            ]
+        );
+        assert!(files.iter().all(|(_, content)| !content.contains("{{day")));
+
+        // Like the other days, the answers on the real input are checked against answers.toml
+        for template in available(&dirs) {
+            let files = render_dir(&find(&template, &dirs).unwrap(), &variables()).unwrap();
+            let (_, lib) = files
+                .iter()
+                .find(|(path, _)| path == Path::new("src/lib.rs"))
+                .unwrap();
+            assert!(lib.contains("fn test_real_input()"), "{}", template);
+            assert!(lib.contains("assert_answer(DAY, Part::A, solve_a(&input));"));
+            assert!(lib.contains("assert_answer(DAY, Part::B, solve_b(&input));"));
+        }
+        assert!(find("unknown", &dirs).is_err());
+        assert_eq!(find("library-style", &dirs).unwrap(), dirs[0].join("lines"));
+    }
+
+    #[test]
+    fn test_override_takes_precedence() {
+        let override_dir =
+            std::env::temp_dir().join(format!("add-day-templates-{}", std::process::id()));
+        fs::create_dir_all(override_dir.join("lines")).unwrap();
+        fs::write(override_dir.join("lines/notes.md.tmpl"), "# {{title}}\n").unwrap();
+
+        let dirs = vec![
+            override_dir.clone(),
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("templates"),
+        ];
+        let files = render_dir(&find("lines", &dirs).unwrap(), &variables()).unwrap();
+        assert_eq!(
+            files,
+            vec![(
+                PathBuf::from("notes.md"),
+                "# The Treachery of Whales\n".to_string()
+            )]
+        );
+        assert_eq!(find("grid", &dirs).unwrap(), dirs[1].join("grid"));
+        fs::remove_dir_all(override_dir).unwrap();
+    }
+}
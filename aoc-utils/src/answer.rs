@@ -0,0 +1,101 @@
+use crate::{Grid, NoAnswer};
+use std::fmt;
+use std::fmt::Display;
+
+/// Answer to a part of a puzzle, so that answers of all days can be compared and displayed alike.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Letters drawn with lit pixels, e.g. day 13 part B. Displayed with `#` for lit and `.` for dark pixels.
+    Bitmap(Grid<bool>),
+}
+
+impl Answer {
+    /// Whether the answer spans multiple lines when displayed.
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Integer(_) => false,
+            Answer::Text(text) => text.trim().contains('\n'),
+            Answer::Bitmap(bitmap) => bitmap.height() > 1,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(i) => write!(f, "{}", i),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Bitmap(bitmap) => {
+                let rendered = bitmap.render(|&lit| if lit { '#' } else { '.' });
+                write!(f, "{}", rendered.trim_end_matches('\n'))
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(i: $t) -> Answer {
+                    Answer::Integer(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(bitmap: Grid<bool>) -> Answer {
+        Answer::Bitmap(bitmap)
+    }
+}
+
+/// Only needed to satisfy the bounds of [`Solution`](crate::Solution), days without part B are
+/// never asked for its answer. Converts to the `-` it is displayed as.
+impl From<NoAnswer> for Answer {
+    fn from(_: NoAnswer) -> Answer {
+        Answer::Text(NoAnswer.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-7i64).to_string(), "-7");
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+
+        let bitmap = Grid::from_rows(vec![vec![true, false], vec![false, true]]);
+        let answer = Answer::from(bitmap);
+        assert_eq!(answer.to_string(), "#.\n.#");
+        assert!(answer.is_multiline());
+        assert!(!Answer::from(1u8).is_multiline());
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(Answer::from(5u32), Answer::from(5i64));
+        assert_ne!(Answer::from(5u32), Answer::from("5"));
+    }
+}
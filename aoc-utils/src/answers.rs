@@ -0,0 +1,239 @@
+use crate::{Answer, Error, Part};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Name of the file in the workspace root holding the expected answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The expected answers of the days, as recorded in answers.toml, optionally with the puzzle title:
+///
+/// ```toml
+/// [day01]
+/// title = "Sonar Sweep"
+/// a = 1448
+/// b = "1471"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: BTreeMap<(u8, Part), String>,
+    titles: BTreeMap<u8, String>,
+}
+
+/// Outcome of comparing an answer with the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// There is no recorded answer for this part.
+    Unknown,
+    Wrong(Mismatch),
+}
+
+/// An answer that differs from the recorded one. Displays as a line by line diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Answers {
+    /// Reads answers.toml from the current directory or its parent, because the
+    /// working directory is inside the package when running tests.
+    /// Returns `None` if the file doesn't exist.
+    pub fn load() -> Result<Option<Answers>, Error> {
+        for path in [
+            PathBuf::from(ANSWERS_FILE),
+            Path::new("..").join(ANSWERS_FILE),
+        ] {
+            if !path.exists() {
+                continue;
+            }
+
+            let content = fs::read_to_string(&path).map_err(|source| Error::Io {
+                path: path.clone(),
+                source,
+            })?;
+            return match Answers::parse(&content) {
+                Ok(answers) => Ok(Some(answers)),
+                Err(message) => Err(Error::InvalidAnswers { path, message }),
+            };
+        }
+
+        Ok(None)
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let table = content
+            .parse::<toml::Table>()
+            .map_err(|e| e.to_string().trim_end().to_string())?;
+
+        let mut expected = BTreeMap::new();
+        let mut titles = BTreeMap::new();
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("Invalid key \"{}\", expected a day like day01", key))?;
+            let parts = value
+                .as_table()
+                .ok_or_else(|| format!("{} has to be a table", key))?;
+
+            for (part_key, answer) in parts {
+                let part = match part_key.as_str() {
+                    "a" => Part::A,
+                    "b" => Part::B,
+                    "title" => {
+                        let title = answer
+                            .as_str()
+                            .ok_or_else(|| format!("{}.title has to be a string", key))?;
+                        titles.insert(day, title.to_string());
+                        continue;
+                    }
+                    _ => return Err(format!("Invalid part {}.{}", key, part_key)),
+                };
+                let answer = match answer {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "Answer {}.{} has to be a string or an integer",
+                            key, part_key
+                        ))
+                    }
+                };
+                expected.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { expected, titles })
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn title(&self, day: u8) -> Option<&str> {
+        self.titles.get(&day).map(|s| s.as_str())
+    }
+
+    /// Compares an answer with the recorded one. Surrounding whitespace is ignored,
+    /// so that multi-line answers can be recorded as multi-line strings.
+    pub fn check(&self, day: u8, part: Part, actual: &str) -> Verdict {
+        match self.expected(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected.trim() == actual.trim() => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(Mismatch {
+                day,
+                part,
+                expected: expected.trim().to_string(),
+                actual: actual.trim().to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} {} differs from the answer in {} (-expected +actual):",
+            self.day, self.part, ANSWERS_FILE
+        )?;
+
+        let expected = self.expected.lines().collect::<Vec<_>>();
+        let actual = self.actual.lines().collect::<Vec<_>>();
+        for i in 0..expected.len().max(actual.len()) {
+            match (expected.get(i), actual.get(i)) {
+                (Some(e), Some(a)) if e == a => write!(f, "\n  {}", e)?,
+                (e, a) => {
+                    if let Some(e) = e {
+                        write!(f, "\n- {}", e)?;
+                    }
+                    if let Some(a) = a {
+                        write!(f, "\n+ {}", a)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Panics with a diff if the answer differs from the one recorded in answers.toml.
+/// Answers that aren't recorded are accepted, so new days don't need an entry right away.
+pub fn assert_answer<A: Into<Answer>>(day: u8, part: Part, actual: A) {
+    let answers = Answers::load().unwrap_or_else(|e| panic!("{}", e));
+    if let Some(answers) = answers {
+        if let Verdict::Wrong(mismatch) = answers.check(day, part, &actual.into().to_string()) {
+            panic!("{}", mismatch);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "[day01]\n\
+                           title = \"Sonar Sweep\"\n\
+                           a = 7\n\
+                           b = \"5\"\n\
+                           \n\
+                           [day13]\n\
+                           b = '''\n\
+                           #..\n\
+                           .#.\n\
+                           '''\n";
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, Part::A, "7"), Verdict::Correct);
+        assert_eq!(answers.check(1, Part::B, "5"), Verdict::Correct);
+        assert_eq!(answers.check(13, Part::B, "\n#..\n.#.\n"), Verdict::Correct);
+        assert_eq!(answers.check(13, Part::A, "1"), Verdict::Unknown);
+        assert_eq!(answers.title(1), Some("Sonar Sweep"));
+        assert_eq!(answers.title(13), None);
+        assert_eq!(
+            answers.check(1, Part::A, "8"),
+            Verdict::Wrong(Mismatch {
+                day: 1,
+                part: Part::A,
+                expected: "7".to_string(),
+                actual: "8".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_mismatch_diff() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        match answers.check(13, Part::B, "#..\n..#") {
+            Verdict::Wrong(mismatch) => assert_eq!(
+                mismatch.to_string(),
+                "Day 13 B differs from the answer in answers.toml (-expected +actual):\n  #..\n- .#.\n+ ..#"
+            ),
+            verdict => panic!("Expected a mismatch, got {:?}", verdict),
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_answers() {
+        assert!(Answers::parse("[one]\na = 1").is_err());
+        assert!(Answers::parse("[day01]\nc = 1").is_err());
+        assert!(Answers::parse("[day01]\na = 1.5").is_err());
+        assert!(Answers::parse("[day01]\ntitle = 1").is_err());
+        assert!(Answers::parse("[day01\na = 1").is_err());
+    }
+
+    #[test]
+    fn test_load_workspace_answers() {
+        let answers = Answers::load().unwrap().unwrap();
+        assert_eq!(answers.expected(1, Part::A), Some("1448"));
+        assert_answer(1, Part::B, 1471);
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{Answer, Part, PuzzleInput, RegisteredSolution};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Minimum, median and maximum of the durations measured over all iterations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "At least one sample is required");
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len() % 2 == 1 {
+            samples[middle]
+        } else {
+            (samples[middle - 1] + samples[middle]) / 2
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            "{{\"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+            self.min.as_nanos(),
+            self.median.as_nanos(),
+            self.max.as_nanos()
+        )
+    }
+}
+
+/// Timings of the phases of a single day. Parts that weren't benchmarked are `None`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part_a: Option<Stats>,
+    pub part_b: Option<Stats>,
+}
+
+/// Times parsing the input, part A and part B separately, each over the given number
+/// of iterations. `part` restricts the benchmark to a single part, parsing is always timed.
+pub fn bench(
+    solution: &RegisteredSolution,
+    input: &PuzzleInput,
+    iterations: usize,
+    part: Option<Part>,
+) -> BenchResult {
+    assert!(iterations > 0, "At least one iteration is required");
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut parsed = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let result = solution.parse(input);
+        parse_samples.push(start.elapsed());
+        // Dropping the previous input happens outside of the measurement
+        parsed = Some(result);
+    }
+    let parsed = parsed.unwrap();
+
+    let part_a = if part != Some(Part::B) {
+        Some(time(iterations, || solution.time_a(&parsed)))
+    } else {
+        None
+    };
+    let part_b = if part != Some(Part::A) && solution.has_part_b() {
+        Some(time(iterations, || solution.time_b(&parsed).unwrap()))
+    } else {
+        None
+    };
+
+    BenchResult {
+        day: solution.day(),
+        iterations,
+        parse: Stats::from_samples(parse_samples),
+        part_a,
+        part_b,
+    }
+}
+
+/// Collects the durations measured by `solve`, the answers are dropped outside of the measurement.
+fn time<F: FnMut() -> (Answer, Duration)>(iterations: usize, mut solve: F) -> Stats {
+    let samples = (0..iterations).map(|_| black_box(solve()).1).collect();
+    Stats::from_samples(samples)
+}
+
+/// Serializes the results as JSON, with all durations in nanoseconds.
+pub fn to_json(results: &[BenchResult]) -> String {
+    let stats_json = |stats: Option<Stats>| match stats {
+        Some(stats) => stats.to_json(),
+        None => "null".to_string(),
+    };
+
+    let mut json = String::from("{\n  \"days\": [");
+    for (i, result) in results.iter().enumerate() {
+        let _ = write!(
+            json,
+            "{}\n    {{\"day\": {}, \"iterations\": {}, \"parse\": {}, \"a\": {}, \"b\": {}}}",
+            if i == 0 { "" } else { "," },
+            result.day,
+            result.iterations,
+            result.parse.to_json(),
+            stats_json(result.part_a),
+            stats_json(result.part_b)
+        );
+    }
+    if !results.is_empty() {
+        json.push_str("\n  ");
+    }
+    json.push_str("]\n}\n");
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NoAnswer, Registry, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+        type AnswerA = u32;
+        type AnswerB = NoAnswer;
+
+        fn parse(input: &PuzzleInput) -> Vec<u32> {
+            input.convert_to_ints_by_line()
+        }
+
+        fn solve_a(numbers: &Vec<u32>) -> u32 {
+            numbers.iter().sum()
+        }
+
+        fn solve_b(_: &Vec<u32>) -> NoAnswer {
+            NoAnswer
+        }
+    }
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(millis(&[5, 1, 3]));
+        assert_eq!(
+            stats,
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            }
+        );
+        let stats = Stats::from_samples(millis(&[4, 1, 2, 8]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+    }
+
+    #[test]
+    fn test_bench_skips_missing_part() {
+        let mut registry = Registry::new();
+        registry.register::<Sum>();
+        let result = bench(registry.get(1).unwrap(), &PuzzleInput::new("1\n2"), 3, None);
+        assert_eq!(result.day, 1);
+        assert_eq!(result.iterations, 3);
+        assert!(result.part_a.is_some());
+        assert!(result.part_b.is_none());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let stats = Stats::from_samples(millis(&[1, 2]));
+        let result = BenchResult {
+            day: 6,
+            iterations: 2,
+            parse: stats,
+            part_a: Some(stats),
+            part_b: None,
+        };
+        let stats_json = "{\"min_ns\": 1000000, \"median_ns\": 1500000, \"max_ns\": 2000000}";
+        assert_eq!(
+            to_json(&[result]),
+            format!(
+                "{{\n  \"days\": [\n    {{\"day\": 6, \"iterations\": 2, \"parse\": {0}, \"a\": {0}, \"b\": null}}\n  ]\n}}\n",
+                stats_json
+            )
+        );
+        assert_eq!(to_json(&[]), "{\n  \"days\": []\n}\n");
+    }
+}
@@ -0,0 +1,75 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Errors that can happen while loading or parsing a puzzle input or the expected answers.
+#[derive(Debug)]
+pub enum Error {
+    /// None of the searched locations contained the input file for the given day.
+    InputNotFound { day: u8, searched: Vec<PathBuf> },
+    /// The input file exists, but could not be read, or a fetched input could not be written.
+    Io { path: PathBuf, source: io::Error },
+    /// A token of the input could not be converted into the requested type.
+    /// Line and column are 1-based and point at the start of the token.
+    Parse {
+        line: usize,
+        column: usize,
+        token: String,
+        message: String,
+    },
+    /// The input ended after the given line, but another blank line separated section was expected.
+    MissingSection { after_line: usize },
+    /// The file with the expected answers exists, but isn't valid.
+    InvalidAnswers { path: PathBuf, message: String },
+    /// Neither `AOC_SESSION` nor ~/.config/aoc/session contain a session token to fetch inputs with.
+    MissingSession,
+    /// Downloading an input failed.
+    Fetch { url: String, message: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InputNotFound { day, searched } => {
+                write!(f, "Puzzle input for day {} does not exist, searched:", day)?;
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+                Ok(())
+            }
+            Error::Io { path, source } => {
+                write!(f, "Unable to access {}: {}", path.display(), source)
+            }
+            Error::Parse {
+                line,
+                column,
+                token,
+                message,
+            } => write!(
+                f,
+                "Invalid token {:?} at line {}, column {}: {}",
+                token, line, column, message
+            ),
+            Error::MissingSection { after_line } => {
+                write!(f, "Expected another section after line {}", after_line)
+            }
+            Error::InvalidAnswers { path, message } => {
+                write!(f, "Invalid answers in {}: {}", path.display(), message)
+            }
+            Error::MissingSession => write!(
+                f,
+                "No session token to fetch inputs with, set AOC_SESSION or write it to ~/.config/aoc/session"
+            ),
+            Error::Fetch { url, message } => write!(f, "Unable to fetch {}: {}", url, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
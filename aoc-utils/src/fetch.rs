@@ -0,0 +1,248 @@
+use crate::{day_path, input_file_name, Error};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Environment variable containing the session token, the value of the `session` cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable overriding the server the inputs are downloaded from.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable overriding the directory downloaded inputs are cached in.
+pub const CACHE_DIR_ENV: &str = "AOC_CACHE_DIR";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Minimum time between two requests, so that the server isn't hammered when fetching many days.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+const USER_AGENT: &str = "github.com/daniel0611/AdventOfCode2021 input fetcher";
+
+/// Downloads puzzle inputs using a session token, caching every input
+/// so that it is only downloaded once.
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+    interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl Fetcher {
+    pub fn new<S: Into<String>>(session: S, cache_dir: PathBuf) -> Fetcher {
+        Fetcher {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: session.into(),
+            cache_dir,
+            interval: DEFAULT_INTERVAL,
+            last_request: None,
+        }
+    }
+
+    /// Creates a fetcher configured by the environment. The session token is read from `AOC_SESSION`
+    /// or ~/.config/aoc/session, `AOC_BASE_URL` and `AOC_CACHE_DIR` override the server
+    /// and the cache directory (~/.cache/aoc by default).
+    pub fn from_env() -> Result<Fetcher, Error> {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let session_file = home.as_ref().map(|home| home.join(".config/aoc/session"));
+
+        let session = match std::env::var(SESSION_ENV) {
+            Ok(session) => session,
+            Err(_) => session_file
+                .as_ref()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .ok_or(Error::MissingSession)?,
+        };
+        let cache_dir = match std::env::var_os(CACHE_DIR_ENV) {
+            Some(dir) => PathBuf::from(dir),
+            None => home.unwrap_or_default().join(".cache/aoc"),
+        };
+
+        let mut fetcher = Fetcher::new(session.trim(), cache_dir);
+        if let Ok(base_url) = std::env::var(BASE_URL_ENV) {
+            fetcher = fetcher.with_base_url(base_url);
+        }
+        Ok(fetcher)
+    }
+
+    pub fn with_base_url<S: Into<String>>(mut self, base_url: S) -> Fetcher {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_interval(mut self, interval: Duration) -> Fetcher {
+        self.interval = interval;
+        self
+    }
+
+    /// Path the input of the day is cached at.
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(input_file_name(day))
+    }
+
+    /// Returns the input of the day from the cache, or downloads and caches it.
+    pub fn fetch(&mut self, year: u16, day: u8) -> Result<String, Error> {
+        let cache_path = self.cache_path(year, day);
+        if let Ok(input) = fs::read_to_string(&cache_path) {
+            return Ok(input);
+        }
+
+        let input = self.download(year, day)?;
+        write_file(&cache_path, &input)?;
+        Ok(input)
+    }
+
+    /// Fetches the input of the day and writes it to where [`PuzzleInput::get_input_for`](crate::PuzzleInput::get_input_for)
+    /// expects it inside the workspace `root`, returning its path.
+    pub fn fetch_to(&mut self, root: &Path, year: u16, day: u8) -> Result<PathBuf, Error> {
+        let input = self.fetch(year, day)?;
+        let path = root.join(day_path(year, day)).join(input_file_name(day));
+        write_file(&path, &input)?;
+        Ok(path)
+    }
+
+    fn download(&mut self, year: u16, day: u8) -> Result<String, Error> {
+        if let Some(last_request) = self.last_request {
+            thread::sleep(self.interval.saturating_sub(last_request.elapsed()));
+        }
+        self.last_request = Some(Instant::now());
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let fetch_error = |message: String| Error::Fetch {
+            url: url.clone(),
+            message,
+        };
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        let mut response = agent
+            .get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::StatusCode(400 | 401) => {
+                    fetch_error("the session token is invalid or expired".to_string())
+                }
+                e => fetch_error(e.to_string()),
+            })?;
+        response
+            .body_mut()
+            .read_to_string()
+            .map_err(|e| fetch_error(e.to_string()))
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), Error> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+
+    /// Local stand-in for the server: answers `count` requests with the given status and body
+    /// and sends the request line and cookie of each request.
+    fn mock_server(count: usize, status: u16, body: &'static str) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for stream in listener.incoming().take(count) {
+                let mut stream = stream.unwrap();
+                let mut request = vec![];
+                for line in BufReader::new(&stream).lines() {
+                    let line = line.unwrap();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if line.starts_with("GET") || line.to_lowercase().starts_with("cookie") {
+                        request.push(line);
+                    }
+                }
+                sender.send(request.join("\n")).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+        (base_url, receiver)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let (base_url, requests) = mock_server(1, 200, "1\n2\n3\n");
+        let dir = temp_dir("cache");
+        let mut fetcher = Fetcher::new("token", dir.join("cache")).with_base_url(base_url);
+
+        assert_eq!(fetcher.fetch(2021, 7).unwrap(), "1\n2\n3\n");
+        assert_eq!(
+            requests.recv().unwrap().to_lowercase(),
+            "get /2021/day/7/input http/1.1\ncookie: session=token"
+        );
+
+        // Only the cache is used from now on, the server would not answer another request
+        let path = fetcher.fetch_to(&dir, 2021, 7).unwrap();
+        assert_eq!(path, dir.join("day07/input_day07.txt"));
+        assert_eq!(fs::read_to_string(path).unwrap(), "1\n2\n3\n");
+        assert!(requests.try_recv().is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_rate_limit() {
+        let (base_url, requests) = mock_server(2, 200, "input");
+        let dir = temp_dir("rate-limit");
+        let mut fetcher = Fetcher::new("token", dir.clone())
+            .with_base_url(base_url)
+            .with_interval(Duration::from_millis(300));
+
+        let start = Instant::now();
+        fetcher.fetch(2021, 1).unwrap();
+        fetcher.fetch(2021, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(requests.iter().take(2).count(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_invalid_session() {
+        let (base_url, _requests) = mock_server(1, 400, "Please log in");
+        let dir = temp_dir("session");
+        let mut fetcher = Fetcher::new("expired", dir.clone()).with_base_url(base_url);
+
+        match fetcher.fetch(2021, 1) {
+            Err(Error::Fetch { message, .. }) => {
+                assert_eq!(message, "the session token is invalid or expired")
+            }
+            _ => panic!("Expected a fetch error"),
+        }
+        assert!(!fetcher.cache_path(2021, 1).exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
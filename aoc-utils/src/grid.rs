@@ -0,0 +1,443 @@
+use crate::{Error, PuzzleInput};
+use std::ops::{Index, IndexMut};
+
+const OFFSETS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// How positions outside of a [`Grid`] are treated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Boundary<T> {
+    /// There is nothing outside of the grid.
+    Bounded,
+    /// The grid repeats in every direction, leaving it on one side enters it on the other side.
+    Wrapping,
+    /// The grid is surrounded by infinitely many cells with the given value.
+    Infinite(T),
+}
+
+/// A rectangular grid of cells, stored row by row. Positions are `(x, y)` with `(0, 0)`
+/// in the top left corner. Neighbors and signed lookups respect the [`Boundary`] of the grid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    boundary: Boundary<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a bounded grid by calling `f` with the position of every cell.
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(width: usize, height: usize, mut f: F) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+            boundary: Boundary::Bounded,
+        }
+    }
+
+    /// Creates a bounded grid from its rows. Panics if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "All rows of a grid need to have the same length"
+        );
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+            boundary: Boundary::Bounded,
+        }
+    }
+
+    /// Parses every character of the input into a cell, with one row per line.
+    /// Characters for which `f` returns `None` and rows of differing length are reported as error.
+    pub fn parse<F: FnMut(char) -> Option<T>>(
+        input: &PuzzleInput,
+        mut f: F,
+    ) -> Result<Grid<T>, Error> {
+        let mut rows = vec![];
+        for (y, line) in input
+            .raw_input()
+            .trim_end_matches('\n')
+            .split('\n')
+            .enumerate()
+        {
+            let line = line.trim_end_matches('\r');
+            let row = line
+                .chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    f(c).ok_or_else(|| Error::Parse {
+                        line: input.first_line() + y,
+                        column: x + 1,
+                        token: c.to_string(),
+                        message: "unexpected character".to_string(),
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(first) = rows.first().map(|first: &Vec<T>| first.len()) {
+                if row.len() != first {
+                    return Err(Error::Parse {
+                        line: input.first_line() + y,
+                        column: 1,
+                        token: line.to_string(),
+                        message: format!("expected a row of {} cells", first),
+                    });
+                }
+            }
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn with_boundary(mut self, boundary: Boundary<T>) -> Grid<T> {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn boundary(&self) -> &Boundary<T> {
+        &self.boundary
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if self.contains(x, y) {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Maps a position that may lie outside of the grid to a cell of the grid.
+    /// Only wrapping grids have a cell for every position.
+    pub fn position(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        match self.boundary {
+            Boundary::Wrapping if width > 0 && height > 0 => {
+                Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize))
+            }
+            _ if x >= 0 && y >= 0 && x < width && y < height => Some((x as usize, y as usize)),
+            _ => None,
+        }
+    }
+
+    /// Value at a position that may lie outside of the grid, see [`Boundary`].
+    pub fn at(&self, x: isize, y: isize) -> Option<&T> {
+        match (self.position(x, y), &self.boundary) {
+            (Some((x, y)), _) => self.get(x, y),
+            (None, Boundary::Infinite(value)) => Some(value),
+            (None, _) => None,
+        }
+    }
+
+    /// The horizontally and vertically adjacent cells of a position.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &OFFSETS_4)
+    }
+
+    /// The horizontally, vertically and diagonally adjacent cells of a position.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &OFFSETS_8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |(dx, dy)| self.position(x as isize + dx, y as isize + dy))
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Applies `f` to every cell, including the value outside of an infinite grid.
+    pub fn map<U, F: FnMut(&T) -> U>(&self, mut f: F) -> Grid<U> {
+        let boundary = match &self.boundary {
+            Boundary::Bounded => Boundary::Bounded,
+            Boundary::Wrapping => Boundary::Wrapping,
+            Boundary::Infinite(value) => Boundary::Infinite(f(value)),
+        };
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            boundary,
+        }
+    }
+
+    /// Renders the grid with one line per row, every line is terminated by a newline.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            output.extend(row.iter().map(&mut f));
+            output.push('\n');
+        }
+        output
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+            boundary: Boundary::Bounded,
+        }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |x, y| self[(y, x)].clone())
+            .with_boundary(self.boundary.clone())
+    }
+
+    /// Folds the bottom part of the grid up along the row `y`, which is removed.
+    /// Cells that end up on top of each other are merged using `combine(upper, lower)`.
+    /// The result is as high as the taller of both parts, with the fold at its bottom edge.
+    pub fn fold_up<F: FnMut(&T, &T) -> T>(&self, y: usize, mut combine: F) -> Grid<T> {
+        assert!(y < self.height, "Row {} is outside of the grid", y);
+        let height = y.max(self.height - 1 - y);
+        Grid::from_fn(self.width, height, |x, folded_y| {
+            // Rows at the same distance from the fold end up on top of each other
+            let distance = height - folded_y;
+            let upper = y.checked_sub(distance).map(|upper_y| &self[(x, upper_y)]);
+            match (upper, self.get(x, y + distance)) {
+                (Some(upper), Some(lower)) => combine(upper, lower),
+                (Some(cell), None) | (None, Some(cell)) => cell.clone(),
+                (None, None) => unreachable!(),
+            }
+        })
+        .with_boundary(self.boundary.clone())
+    }
+
+    /// Folds the right part of the grid to the left along the column `x`, which is removed.
+    /// Cells that end up on top of each other are merged using `combine(left, right)`.
+    /// The result is as wide as the wider of both parts, with the fold at its right edge.
+    pub fn fold_left<F: FnMut(&T, &T) -> T>(&self, x: usize, mut combine: F) -> Grid<T> {
+        assert!(x < self.width, "Column {} is outside of the grid", x);
+        let width = x.max(self.width - 1 - x);
+        Grid::from_fn(width, self.height, |folded_x, y| {
+            let distance = width - folded_x;
+            let left = x.checked_sub(distance).map(|left_x| &self[(left_x, y)]);
+            match (left, self.get(x + distance, y)) {
+                (Some(left), Some(right)) => combine(left, right),
+                (Some(cell), None) | (None, Some(cell)) => cell.clone(),
+                (None, None) => unreachable!(),
+            }
+        })
+        .with_boundary(self.boundary.clone())
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single digits like `2199943210`.
+    pub fn parse_digits(input: &PuzzleInput) -> Result<Grid<u8>, Error> {
+        Grid::parse(input, |c| c.to_digit(10).map(|d| d as u8))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("Position ({}, {}) is outside of the grid", x, y))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("Position ({}, {}) is outside of the grid", x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Grid<u8> {
+        Grid::parse_digits(&PuzzleInput::new(s)).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)),);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        match Grid::parse_digits(&PuzzleInput::new("12\n3x")) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            _ => panic!("Expected a parse error"),
+        }
+        match Grid::parse_digits(&PuzzleInput::new("12\n345")) {
+            Err(Error::Parse { line, message, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(message, "expected a row of 2 cells");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_bounded_neighbors() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(
+            grid.neighbors8(2, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = digits("123\n456").with_boundary(Boundary::Wrapping);
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (2, 0), (1, 0), (0, 1)]
+        );
+        assert_eq!(grid.at(-1, 2), Some(&3));
+        assert_eq!(grid.position(7, -3), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_infinite() {
+        let grid = digits("12\n34").with_boundary(Boundary::Infinite(0));
+        assert_eq!(grid.at(1, 1), Some(&4));
+        assert_eq!(grid.at(-5, 100), Some(&0));
+        assert_eq!(grid.neighbors8(0, 0).count(), 3);
+
+        let mapped = grid.map(|v| v + 1);
+        assert_eq!(mapped.at(-1, -1), Some(&1));
+        assert_eq!(digits("1").at(-1, 0), None);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456");
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.transpose(), digits("14\n25\n36"));
+    }
+
+    #[test]
+    fn test_fold() {
+        let grid = Grid::parse(&PuzzleInput::new("#....\n.....\n.....\n...#."), |c| {
+            Some(c == '#')
+        })
+        .unwrap();
+        let folded = grid.fold_up(2, |a, b| *a || *b);
+        assert_eq!(
+            folded.render(|&b| if b { '#' } else { '.' }),
+            "#....\n...#.\n"
+        );
+        let folded = folded.fold_left(2, |a, b| *a || *b);
+        assert_eq!(folded.render(|&b| if b { '#' } else { '.' }), "#.\n.#\n");
+
+        // The part beyond the fold is larger, so it sticks out past the other part
+        let folded = grid.fold_up(1, |a, b| *a || *b);
+        assert_eq!(
+            folded.render(|&b| if b { '#' } else { '.' }),
+            "...#.\n#....\n"
+        );
+        let folded = grid.fold_left(3, |a, b| *a || *b);
+        assert_eq!(
+            folded.render(|&b| if b { '#' } else { '.' }),
+            "#..\n...\n...\n...\n"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 4 is outside of the grid")]
+    fn test_fold_outside() {
+        Grid::new(2, 4, false).fold_up(4, |a, b| *a || *b);
+    }
+}
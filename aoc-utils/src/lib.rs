@@ -1,45 +1,310 @@
+mod answer;
+mod answers;
+pub mod bench;
+mod error;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+mod grid;
+pub mod log;
+pub mod ocr;
+mod paths;
+pub mod pattern;
+mod solution;
+
+use std::fmt::Display;
 use std::fs;
-use std::path::Path;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub use answer::Answer;
+pub use answers::{assert_answer, Answers, Mismatch, Verdict, ANSWERS_FILE};
+pub use error::Error;
+pub use grid::{Boundary, Grid};
+pub use paths::{
+    day_dir_name, day_path, input_file_name, input_search_paths, package_name, DEFAULT_YEAR,
+};
+pub use pattern::FromPuzzleLine;
+pub use solution::{run, NoAnswer, ParsedInput, Part, RegisteredSolution, Registry, Solution};
+
+/// Environment variable naming a directory that contains the input_day<day_number>.txt files,
+/// and the ones of other years than [`DEFAULT_YEAR`] in year<year> subdirectories.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Text of an input. Memory-mapped files are borrowed from instead of being copied.
+enum Content {
+    Owned(String),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Content {
+    fn as_str(&self) -> &str {
+        match self {
+            Content::Owned(content) => content,
+            // Safety: the mapping was checked to be valid UTF-8 when the file was mapped
+            #[cfg(feature = "mmap")]
+            Content::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+}
 
 pub struct PuzzleInput {
-    pub raw_input: String,
+    content: Content,
+    /// Number of lines before this input in the original input, if it is a section of it.
+    line_offset: usize,
 }
 
 impl PuzzleInput {
     pub fn new<S: Into<String>>(content: S) -> PuzzleInput {
         PuzzleInput {
-            raw_input: content.into(),
+            content: Content::Owned(content.into()),
+            line_offset: 0,
         }
     }
 
+    /// The whole text of the input.
+    pub fn raw_input(&self) -> &str {
+        self.content.as_str()
+    }
+
     /// Reads the puzzle input for the puzzle with the given day from input_day<day_number>.txt
+    ///
+    /// Panics if the input can't be found or read, see [`PuzzleInput::try_get_input`].
     pub fn get_input(day: u8) -> PuzzleInput {
-        let path_string = format!("input_day{:02}.txt", day);
-        let path = Path::new(&path_string);
-
-        // When running in tests, the working directory is inside the package, but
-        // when running the actual puzzle, it's in the workspace root.
-        let outside_path_str = format!("day{:02}/{}", day, path.display());
-        let outside_path = Path::new(&outside_path_str);
-
-        if path.exists() {
-            let content = fs::read_to_string(path).expect("Unable to read file");
-            PuzzleInput::new(content)
-        } else if outside_path.exists() {
-            let content = fs::read_to_string(outside_path).expect("Unable to read file");
-            PuzzleInput::new(content)
-        } else {
-            let cwd = std::env::current_dir().unwrap();
-            panic!(
-                "Puzzle input at {}/{} does not exist",
-                cwd.display(),
-                path.display()
-            );
+        Self::get_input_for(DEFAULT_YEAR, day)
+    }
+
+    /// Reads the puzzle input for the given day of the given year, see [`PuzzleInput::get_input`].
+    pub fn get_input_for(year: u16, day: u8) -> PuzzleInput {
+        Self::try_get_input_for(year, day, None).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reads the puzzle input for the given day, using the first command line argument
+    /// as explicit input path if there is one. Meant to be used by the day binaries.
+    ///
+    /// Panics if the input can't be found or read, see [`PuzzleInput::try_get_input_from`].
+    pub fn from_args(day: u8) -> PuzzleInput {
+        Self::from_args_for(DEFAULT_YEAR, day)
+    }
+
+    /// Reads the puzzle input for the given day of the given year, see [`PuzzleInput::from_args`].
+    pub fn from_args_for(year: u16, day: u8) -> PuzzleInput {
+        let path = std::env::args().nth(1);
+        Self::try_get_input_for(year, day, path.as_deref()).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Reads the puzzle input for the puzzle with the given day from input_day<day_number>.txt
+    /// and reports which paths were searched if it doesn't exist.
+    pub fn try_get_input(day: u8) -> Result<PuzzleInput, Error> {
+        Self::try_get_input_from(day, None)
+    }
+
+    /// Reads the puzzle input for the given day. The first of these that applies is used:
+    ///
+    /// 1. `path`, if it is given and isn't `-`
+    /// 2. input_day<day_number>.txt inside the directory named by the `AOC_INPUT_DIR` environment variable
+    /// 3. stdin, if `path` is `-`
+    /// 4. input_day<day_number>.txt in the current directory
+    /// 5. day<day_number>/input_day<day_number>.txt
+    pub fn try_get_input_from(day: u8, path: Option<&str>) -> Result<PuzzleInput, Error> {
+        Self::try_get_input_for(DEFAULT_YEAR, day, path)
+    }
+
+    /// Reads the puzzle input for the given day of the given year, see [`PuzzleInput::try_get_input_from`].
+    /// Days of other years than [`DEFAULT_YEAR`] are searched in year<year>/day<day_number>
+    /// and in the year<year> subdirectory of `AOC_INPUT_DIR`.
+    pub fn try_get_input_for(year: u16, day: u8, path: Option<&str>) -> Result<PuzzleInput, Error> {
+        let input_dir = std::env::var_os(INPUT_DIR_ENV).map(PathBuf::from);
+        Self::resolve(year, day, path, input_dir, io::stdin())
+    }
+
+    fn resolve<R: Read>(
+        year: u16,
+        day: u8,
+        path: Option<&str>,
+        input_dir: Option<PathBuf>,
+        mut stdin: R,
+    ) -> Result<PuzzleInput, Error> {
+        match path {
+            Some("-") | None => (),
+            Some(path) => return Self::read_file(Path::new(path)),
+        }
+
+        let mut searched = vec![];
+
+        if let Some(input_dir) = input_dir {
+            let input_dir = if year == DEFAULT_YEAR {
+                input_dir
+            } else {
+                input_dir.join(format!("year{}", year))
+            };
+            let env_path = input_dir.join(input_file_name(day));
+            if env_path.exists() {
+                return Self::read_file(&env_path);
+            }
+            searched.push(env_path);
+        }
+
+        if path == Some("-") {
+            let mut content = String::new();
+            return match stdin.read_to_string(&mut content) {
+                Ok(_) => Ok(PuzzleInput::new(content)),
+                Err(source) => Err(Error::Io {
+                    path: PathBuf::from("-"),
+                    source,
+                }),
+            };
+        }
+
+        for fallback in input_search_paths(year, day) {
+            if fallback.exists() {
+                return Self::read_file(&fallback);
+            }
+            searched.push(fallback);
+        }
+
+        let cwd = std::env::current_dir().unwrap_or_default();
+        Err(Error::InputNotFound {
+            day,
+            searched: searched.iter().map(|p| cwd.join(p)).collect(),
+        })
+    }
+
+    fn read_file(path: &Path) -> Result<PuzzleInput, Error> {
+        match Self::read_content(path) {
+            Ok(content) => Ok(PuzzleInput {
+                content,
+                line_offset: 0,
+            }),
+            Err(source) => Err(Error::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    #[cfg(not(feature = "mmap"))]
+    fn read_content(path: &Path) -> io::Result<Content> {
+        fs::read_to_string(path).map(Content::Owned)
+    }
+
+    /// Maps the file into memory, so that large inputs aren't copied.
+    /// Empty files can't be mapped and are read instead.
+    #[cfg(feature = "mmap")]
+    fn read_content(path: &Path) -> io::Result<Content> {
+        let file = fs::File::open(path)?;
+        if file.metadata()?.len() == 0 {
+            return Ok(Content::Owned(String::new()));
+        }
+        // Safety: the input files aren't modified while they are used
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        match std::str::from_utf8(&map) {
+            Ok(_) => Ok(Content::Mapped(map)),
+            Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
+    }
+
+    /// Line number of the first line of this input in the original input, starting at 1.
+    pub fn first_line(&self) -> usize {
+        self.line_offset + 1
+    }
+
+    /// Splits the input into its blocks of lines that are separated by blank lines.
+    /// Errors in the sections report their line in the original input.
+    pub fn sections(&self) -> Vec<PuzzleInput> {
+        let lines = self.raw_input().split('\n').collect::<Vec<_>>();
+        let mut sections = vec![];
+        let mut start = None;
+        for (i, line) in lines.iter().enumerate() {
+            match (line.trim().is_empty(), start) {
+                (true, Some(first)) => {
+                    sections.push(self.slice_lines(&lines, first, i));
+                    start = None;
+                }
+                (false, None) => start = Some(i),
+                _ => (),
+            }
+        }
+        if let Some(first) = start {
+            sections.push(self.slice_lines(&lines, first, lines.len()));
+        }
+
+        sections
+    }
+
+    /// Splits the input at the first blank line into the header section and the body,
+    /// which contains everything after it, including further sections.
+    pub fn header_body(&self) -> Result<(PuzzleInput, PuzzleInput), Error> {
+        let lines = self.raw_input().split('\n').collect::<Vec<_>>();
+        let is_blank = |line: &&str| line.trim().is_empty();
+
+        let header_start = lines
+            .iter()
+            .position(|l| !is_blank(l))
+            .unwrap_or(lines.len());
+        let header_end = lines[header_start..]
+            .iter()
+            .position(is_blank)
+            .map_or(lines.len(), |i| header_start + i);
+        let body_start = lines[header_end..]
+            .iter()
+            .position(|l| !is_blank(l))
+            .map(|i| header_end + i)
+            .ok_or(Error::MissingSection {
+                after_line: self.line_offset + header_end,
+            })?;
+
+        Ok((
+            self.slice_lines(&lines, header_start, header_end),
+            self.slice_lines(&lines, body_start, lines.len()),
+        ))
+    }
+
+    /// Splits the input like [`PuzzleInput::header_body`] and parses both parts.
+    pub fn parse_header_body<H, B, FH, FB>(
+        &self,
+        parse_header: FH,
+        parse_body: FB,
+    ) -> Result<(H, B), Error>
+    where
+        FH: FnOnce(&PuzzleInput) -> Result<H, Error>,
+        FB: FnOnce(&PuzzleInput) -> Result<B, Error>,
+    {
+        let (header, body) = self.header_body()?;
+        Ok((parse_header(&header)?, parse_body(&body)?))
+    }
+
+    fn slice_lines(&self, lines: &[&str], start: usize, end: usize) -> PuzzleInput {
+        PuzzleInput {
+            content: Content::Owned(lines[start..end].join("\n")),
+            line_offset: self.line_offset + start,
         }
     }
 
     pub fn lines(&self) -> Vec<String> {
-        self.raw_input.lines().map(|s| s.to_string()).collect()
+        self.lines_iter().map(|s| s.to_string()).collect()
+    }
+
+    /// Borrowed variant of [`PuzzleInput::lines`] that doesn't allocate.
+    pub fn lines_iter(&self) -> impl Iterator<Item = &str> {
+        self.raw_input().lines()
+    }
+
+    /// Rows of the input as bytes, without trailing blank lines.
+    /// Meant for grids of ASCII characters that are indexed by position.
+    pub fn bytes_grid(&self) -> Vec<&[u8]> {
+        self.raw_input()
+            .trim_end()
+            .lines()
+            .map(|line| line.as_bytes())
+            .collect()
+    }
+
+    /// Splits the input at every occurrence of `separator`, ignoring trailing whitespace
+    /// of the input, without allocating the parts.
+    pub fn split_str<'a>(&'a self, separator: &'a str) -> impl Iterator<Item = &'a str> {
+        self.raw_input().trim_end().split(separator)
     }
 
     pub fn convert_to_ints_by_line<S: std::str::FromStr>(&self) -> Vec<S> {
@@ -50,12 +315,102 @@ impl PuzzleInput {
     }
 
     pub fn convert_to_ints<S: std::str::FromStr>(&self) -> Vec<S> {
-        self.raw_input
+        self.raw_input()
             .split(',')
             .map(|s| s.to_string())
             .filter_map(|s| s.parse::<S>().ok())
             .collect()
     }
+
+    /// Strict variant of [`PuzzleInput::convert_to_ints_by_line`].
+    /// Blank lines are skipped, every other line has to parse or an error is returned.
+    pub fn try_convert_to_ints_by_line<S>(&self) -> Result<Vec<S>, Error>
+    where
+        S: FromStr,
+        S::Err: Display,
+    {
+        let mut offset = 0;
+        let mut values = vec![];
+        for line in self.raw_input().split('\n') {
+            let token = line.trim();
+            if !token.is_empty() {
+                let token_offset = offset + (line.len() - line.trim_start().len());
+                values.push(self.parse_token(token, token_offset)?);
+            }
+            offset += line.len() + 1;
+        }
+
+        Ok(values)
+    }
+
+    /// Strict variant of [`PuzzleInput::convert_to_ints`].
+    /// Surrounding whitespace of each token is ignored, anything else has to parse.
+    pub fn try_convert_to_ints<S>(&self) -> Result<Vec<S>, Error>
+    where
+        S: FromStr,
+        S::Err: Display,
+    {
+        let mut offset = 0;
+        let mut values = vec![];
+        for part in self.raw_input().split(',') {
+            let token_offset = offset + (part.len() - part.trim_start().len());
+            values.push(self.parse_token(part.trim(), token_offset)?);
+            offset += part.len() + 1;
+        }
+
+        Ok(values)
+    }
+
+    /// Parses every line into a record, see [`FromPuzzleLine`] and [`from_puzzle_line!`].
+    /// Blank lines are skipped, errors point to the line in the original input.
+    pub fn parse_lines<T: FromPuzzleLine>(&self) -> Result<Vec<T>, Error> {
+        self.raw_input()
+            .split('\n')
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                T::from_line(line.trim_end()).map_err(|e| match e {
+                    Error::Parse {
+                        line,
+                        column,
+                        token,
+                        message,
+                    } => Error::Parse {
+                        line: line + self.line_offset + i,
+                        column,
+                        token,
+                        message,
+                    },
+                    e => e,
+                })
+            })
+            .collect()
+    }
+
+    fn parse_token<S>(&self, token: &str, offset: usize) -> Result<S, Error>
+    where
+        S: FromStr,
+        S::Err: Display,
+    {
+        token.parse::<S>().map_err(|e| {
+            let (line, column) = self.position(offset);
+            Error::Parse {
+                line,
+                column,
+                token: token.to_string(),
+                message: e.to_string(),
+            }
+        })
+    }
+
+    /// Converts a byte offset into the raw input into a 1-based line and column
+    /// of the original input.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let before = &self.raw_input()[..offset];
+        let line = self.line_offset + before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        (line, before[line_start..].chars().count() + 1)
+    }
 }
 
 #[cfg(test)]
@@ -64,7 +419,25 @@ mod tests {
     #[test]
     fn test_get_input_0_success() {
         let input = super::PuzzleInput::get_input(0);
-        assert_eq!(input.raw_input, "Hello, this is a test\n");
+        assert_eq!(input.raw_input(), "Hello, this is a test\n");
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_read_file_maps_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-utils-mmap-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("input.txt"), "1\n2\n").unwrap();
+        std::fs::write(dir.join("empty.txt"), "").unwrap();
+        std::fs::write(dir.join("binary.txt"), [0xff, 0xfe]).unwrap();
+
+        let input = super::PuzzleInput::read_file(&dir.join("input.txt")).unwrap();
+        assert!(matches!(input.content, super::Content::Mapped(_)));
+        assert_eq!(input.lines_iter().collect::<Vec<_>>(), vec!["1", "2"]);
+        let input = super::PuzzleInput::read_file(&dir.join("empty.txt")).unwrap();
+        assert_eq!(input.raw_input(), "");
+        assert!(super::PuzzleInput::read_file(&dir.join("binary.txt")).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
     }
 
     // File for puzzle 1 doesn't exist in this directory, so should panic
@@ -73,4 +446,202 @@ mod tests {
     fn test_get_input_1b_fail() {
         super::PuzzleInput::get_input(1);
     }
+
+    #[test]
+    fn test_try_get_input_reports_searched_paths() {
+        match super::PuzzleInput::try_get_input(1) {
+            Err(super::Error::InputNotFound { day, searched }) => {
+                assert_eq!(day, 1);
+                assert!(searched[0].ends_with("input_day01.txt"));
+                assert!(searched[1].ends_with("day01/input_day01.txt"));
+            }
+            _ => panic!("Expected InputNotFound"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_explicit_path_first() {
+        let input = super::PuzzleInput::resolve(
+            super::DEFAULT_YEAR,
+            0,
+            Some("input_day00.txt"),
+            Some(".".into()),
+            &b"stdin"[..],
+        )
+        .unwrap();
+        assert_eq!(input.raw_input(), "Hello, this is a test\n");
+    }
+
+    #[test]
+    fn test_resolve_input_dir_before_stdin() {
+        let input_dir = std::env::current_dir().unwrap();
+        let input = super::PuzzleInput::resolve(
+            super::DEFAULT_YEAR,
+            0,
+            Some("-"),
+            Some(input_dir),
+            &b"stdin"[..],
+        )
+        .unwrap();
+        assert_eq!(input.raw_input(), "Hello, this is a test\n");
+    }
+
+    #[test]
+    fn test_resolve_other_year() {
+        match super::PuzzleInput::resolve(2022, 1, None, Some("/inputs".into()), &b""[..]) {
+            Err(super::Error::InputNotFound { searched, .. }) => {
+                assert!(searched[0].ends_with("/inputs/year2022/input_day01.txt"));
+                assert!(searched[2].ends_with("year2022/day01/input_day01.txt"));
+            }
+            _ => panic!("Expected InputNotFound"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_stdin() {
+        let input = super::PuzzleInput::resolve(
+            super::DEFAULT_YEAR,
+            1,
+            Some("-"),
+            Some("/nonexistent".into()),
+            &b"1\n2"[..],
+        )
+        .unwrap();
+        assert_eq!(input.raw_input(), "1\n2");
+    }
+
+    #[test]
+    fn test_resolve_searches_input_dir() {
+        match super::PuzzleInput::resolve(
+            super::DEFAULT_YEAR,
+            1,
+            None,
+            Some("/nonexistent".into()),
+            &b""[..],
+        ) {
+            Err(super::Error::InputNotFound { searched, .. }) => {
+                assert_eq!(searched.len(), 3);
+                assert_eq!(
+                    searched[0],
+                    std::path::Path::new("/nonexistent/input_day01.txt")
+                );
+            }
+            _ => panic!("Expected InputNotFound"),
+        }
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = super::PuzzleInput::new("1,2\n\n\n3 4\n5 6\n\n7\n");
+        let sections = input.sections();
+        let raw = sections.iter().map(|s| s.raw_input()).collect::<Vec<_>>();
+        assert_eq!(raw, vec!["1,2", "3 4\n5 6", "7"]);
+        let first_lines = sections.iter().map(|s| s.first_line()).collect::<Vec<_>>();
+        assert_eq!(first_lines, vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn test_section_errors_report_original_line() {
+        let input = super::PuzzleInput::new("header\n\n1\n2\n\n3\nx4");
+        let last = &input.sections()[2];
+        match last.try_convert_to_ints_by_line::<u8>() {
+            Err(super::Error::Parse { line, column, .. }) => assert_eq!((line, column), (7, 1)),
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_parse_header_body() {
+        let input = super::PuzzleInput::new("7,4,9\n\n1 2\n\n3 4\n");
+        let (numbers, boards) = input
+            .parse_header_body(
+                |header| header.try_convert_to_ints::<u8>(),
+                |body| Ok(body.sections().len()),
+            )
+            .unwrap();
+        assert_eq!(numbers, vec![7, 4, 9]);
+        assert_eq!(boards, 2);
+
+        let (_, body) = input.header_body().unwrap();
+        assert_eq!(body.first_line(), 3);
+        assert_eq!(body.raw_input(), "1 2\n\n3 4\n");
+    }
+
+    #[test]
+    fn test_header_body_requires_body() {
+        match super::PuzzleInput::new("a\nb\n\n").header_body() {
+            Err(e) => assert_eq!(e.to_string(), "Expected another section after line 2"),
+            Ok(_) => panic!("Expected a missing section"),
+        }
+    }
+
+    #[test]
+    fn test_borrowed_lines() {
+        let input = super::PuzzleInput::new("ab\ncd\n\n");
+        assert_eq!(input.lines_iter().collect::<Vec<_>>(), vec!["ab", "cd", ""]);
+        assert_eq!(input.bytes_grid(), vec![b"ab", b"cd"]);
+
+        let input = super::PuzzleInput::new("1 -> 2 -> 3\n");
+        assert_eq!(
+            input.split_str(" -> ").collect::<Vec<_>>(),
+            vec!["1", "2", "3"]
+        );
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        struct Range(u32, u32);
+        crate::from_puzzle_line!(Range, "{}-{}", |start: u32, end: u32| Range(start, end));
+
+        let input = super::PuzzleInput::new("1-3\n\n5-7\n");
+        let ranges = input.parse_lines::<Range>().unwrap();
+        assert_eq!(
+            ranges.iter().map(|r| (r.0, r.1)).collect::<Vec<_>>(),
+            vec![(1, 3), (5, 7)]
+        );
+
+        let (_, body) = super::PuzzleInput::new("header\n\n1-3\n5-x")
+            .header_body()
+            .unwrap();
+        assert_eq!(
+            body.parse_lines::<Range>().err().unwrap().to_string(),
+            "Invalid token \"x\" at line 4, column 3: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_try_convert_to_ints() {
+        let input = super::PuzzleInput::new("3,4, 3,1,2\n");
+        assert_eq!(
+            input.try_convert_to_ints::<u8>().unwrap(),
+            vec![3, 4, 3, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_try_convert_to_ints_rejects_token() {
+        let input = super::PuzzleInput::new("1,2\n,3x,4");
+        match input.try_convert_to_ints::<u8>() {
+            Err(super::Error::Parse {
+                line,
+                column,
+                token,
+                ..
+            }) => {
+                assert_eq!((line, column), (2, 2));
+                assert_eq!(token, "3x");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_try_convert_to_ints_by_line_rejects_token() {
+        let input = super::PuzzleInput::new("199\n200\n\n 208x\n210\n");
+        let error = input.try_convert_to_ints_by_line::<i32>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"208x\" at line 4, column 2: invalid digit found in string"
+        );
+    }
 }
@@ -0,0 +1,101 @@
+//! Debug output of the solutions, silent unless enabled with the `AOC_LOG` environment
+//! variable (`debug` or `trace`) or [`set_level`]. Messages are written to stderr so that
+//! they never mix with the answers.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Environment variable selecting the most detailed [`Level`] that is logged.
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// How detailed a message is, every level includes the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 1,
+    /// Intermediate results, e.g. the dimensions of a grid.
+    Debug = 2,
+    /// Output of every step, e.g. each step of a simulation.
+    Trace = 3,
+}
+
+/// 0 until the level was read from the environment.
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+impl Level {
+    fn from_u8(level: u8) -> Level {
+        match level {
+            2 => Level::Debug,
+            3 => Level::Trace,
+            _ => Level::Off,
+        }
+    }
+
+    /// Parses the value of `AOC_LOG`, anything unknown disables logging.
+    pub fn parse(s: &str) -> Level {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "debug" => Level::Debug,
+            "trace" => Level::Trace,
+            _ => Level::Off,
+        }
+    }
+}
+
+/// The most detailed level that is logged, read from `AOC_LOG` on first use.
+pub fn level() -> Level {
+    match LEVEL.load(Ordering::Relaxed) {
+        0 => {
+            let level = std::env::var(LOG_ENV)
+                .map(|value| Level::parse(&value))
+                .unwrap_or(Level::Off);
+            LEVEL.store(level as u8, Ordering::Relaxed);
+            level
+        }
+        level => Level::from_u8(level),
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+/// Writes a message to stderr if debug output is enabled, takes the same arguments as `eprintln!`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Writes a message to stderr if trace output is enabled, takes the same arguments as `eprintln!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(Level::parse("Debug"), Level::Debug);
+        assert_eq!(Level::parse("trace\n"), Level::Trace);
+        assert_eq!(Level::parse("verbose"), Level::Off);
+
+        set_level(Level::Debug);
+        assert!(enabled(Level::Debug));
+        assert!(!enabled(Level::Trace));
+        assert!(!enabled(Level::Off));
+        set_level(Level::Off);
+        assert!(!enabled(Level::Debug));
+    }
+}
@@ -0,0 +1,246 @@
+//! Recognition of the capital letters some puzzles draw with lit pixels instead of answering
+//! with text, e.g. day 13 of 2021. Only the letters that appeared in puzzles are known.
+
+use crate::Grid;
+
+/// A font with letters of a fixed size, placed next to each other every `pitch` columns.
+struct Font {
+    width: usize,
+    height: usize,
+    pitch: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The font used by most puzzles, e.g. 2016 day 8, 2019 day 8 and 11, 2021 day 13 and 2022 day 10.
+const FONT_4X6: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The large font of 2018 day 10.
+const FONT_6X10: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            &[
+                "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'B',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+                "#....#", "#####.",
+            ],
+        ),
+        (
+            'C',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#....#", ".####.",
+            ],
+        ),
+        (
+            'E',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'F',
+            &[
+                "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'G',
+            &[
+                ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+                "#...##", ".###.#",
+            ],
+        ),
+        (
+            'H',
+            &[
+                "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'J',
+            &[
+                "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+                "#...#.", ".###..",
+            ],
+        ),
+        (
+            'K',
+            &[
+                "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+                "#...#.", "#....#",
+            ],
+        ),
+        (
+            'L',
+            &[
+                "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+        (
+            'N',
+            &[
+                "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+                "#...##", "#....#",
+            ],
+        ),
+        (
+            'P',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+                "#.....", "#.....",
+            ],
+        ),
+        (
+            'R',
+            &[
+                "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'X',
+            &[
+                "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+                "#....#", "#....#",
+            ],
+        ),
+        (
+            'Z',
+            &[
+                "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+                "#.....", "######",
+            ],
+        ),
+    ],
+};
+
+/// Reads the letters drawn by the lit pixels of the bitmap. The font is chosen by the height
+/// of the drawing, which may be surrounded by dark pixels. Every known letter has a lit pixel
+/// in its first column, so the first letter starts at the leftmost lit pixel.
+/// Returns `None` if a letter isn't known or the height doesn't match a font.
+pub fn recognize(bitmap: &Grid<bool>) -> Option<String> {
+    let lit = bitmap
+        .iter()
+        .filter(|&(_, &lit)| lit)
+        .map(|(position, _)| position)
+        .collect::<Vec<_>>();
+    let min_x = lit.iter().map(|&(x, _)| x).min()?;
+    let max_x = lit.iter().map(|&(x, _)| x).max()?;
+    let min_y = lit.iter().map(|&(_, y)| y).min()?;
+    let max_y = lit.iter().map(|&(_, y)| y).max()?;
+
+    let font = [FONT_4X6, FONT_6X10]
+        .into_iter()
+        .find(|font| font.height == max_y - min_y + 1)?;
+    let letters = (max_x - min_x + 1 + font.pitch - font.width).div_ceil(font.pitch);
+
+    (0..letters)
+        .map(|i| {
+            let left = min_x + i * font.pitch;
+            let rows = (min_y..=max_y)
+                .map(|y| {
+                    (left..left + font.width)
+                        .map(|x| match bitmap.get(x, y) {
+                            Some(true) => '#',
+                            _ => '.',
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>();
+            font.glyphs
+                .iter()
+                .find(|(_, glyph)| *glyph == rows.as_slice())
+                .map(|&(letter, _)| letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PuzzleInput;
+
+    fn bitmap(picture: &str) -> Grid<bool> {
+        Grid::parse(&PuzzleInput::new(picture), |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_recognize_4x6() {
+        let picture = "..##..##...##....##.####.####.#..#.#..#.\n\
+                       ...#.#..#.#..#....#.#....#....#.#..#..#.\n\
+                       ...#.#....#..#....#.###..###..##...#..#.\n\
+                       ...#.#.##.####....#.#....#....#.#..#..#.\n\
+                       #..#.#..#.#..#.#..#.#....#....#.#..#..#.\n\
+                       .##...###.#..#..##..####.#....#..#..##..";
+        assert_eq!(recognize(&bitmap(picture)), Some("JGAJEFKU".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_6x10_with_margin() {
+        let picture = "..................\n\
+                       ...#....#..######.\n\
+                       ...#....#.......#.\n\
+                       ...#....#.......#.\n\
+                       ...#....#......#..\n\
+                       ...######.....#...\n\
+                       ...#....#....#....\n\
+                       ...#....#...#.....\n\
+                       ...#....#..#......\n\
+                       ...#....#..#......\n\
+                       ...#....#..######.\n\
+                       ..................";
+        assert_eq!(recognize(&bitmap(picture)), Some("HZ".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_letters() {
+        let square = "#####\n#...#\n#...#\n#...#\n#####";
+        assert_eq!(recognize(&bitmap(square)), None);
+        assert_eq!(recognize(&bitmap("....\n....")), None);
+
+        // A 4x6 O next to a letter that isn't known
+        let picture = ".##..#..#\n\
+                       #..#.#..#\n\
+                       #..#.####\n\
+                       #..#.....\n\
+                       #..#.####\n\
+                       .##......";
+        assert_eq!(recognize(&bitmap(picture)), None);
+    }
+}
@@ -0,0 +1,61 @@
+use std::path::{Path, PathBuf};
+
+/// Year whose days live directly in the workspace root, days of other years live in `year<year>/`.
+pub const DEFAULT_YEAR: u16 = 2021;
+
+/// Name of the directory of a day, e.g. `day07`.
+pub fn day_dir_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Directory of a day relative to the workspace root, e.g. `day07` or `year2022/day07`.
+pub fn day_path(year: u16, day: u8) -> PathBuf {
+    if year == DEFAULT_YEAR {
+        PathBuf::from(day_dir_name(day))
+    } else {
+        Path::new(&format!("year{}", year)).join(day_dir_name(day))
+    }
+}
+
+/// Name of the package of a day, e.g. `day07` or `year2022-day07`,
+/// as package names have to be unique in the workspace.
+pub fn package_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        day_dir_name(day)
+    } else {
+        format!("year{}-{}", year, day_dir_name(day))
+    }
+}
+
+/// Name of the input file of a day, e.g. `input_day07.txt`.
+pub fn input_file_name(day: u8) -> String {
+    format!("input_day{:02}.txt", day)
+}
+
+/// Paths relative to the working directory at which the input of a day is searched by default.
+/// When running in tests, the working directory is inside the package, but
+/// when running the actual puzzle, it's in the workspace root.
+pub fn input_search_paths(year: u16, day: u8) -> [PathBuf; 2] {
+    let file_name = input_file_name(day);
+    [
+        PathBuf::from(&file_name),
+        day_path(year, day).join(file_name),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_of_years() {
+        assert_eq!(day_path(2021, 7), Path::new("day07"));
+        assert_eq!(day_path(2022, 7), Path::new("year2022/day07"));
+        assert_eq!(package_name(2021, 25), "day25");
+        assert_eq!(package_name(2022, 1), "year2022-day01");
+        assert_eq!(
+            input_search_paths(2022, 7)[1],
+            Path::new("year2022/day07/input_day07.txt")
+        );
+    }
+}
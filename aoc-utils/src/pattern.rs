@@ -0,0 +1,218 @@
+use crate::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Types that can be parsed from a single line of the puzzle input,
+/// see [`PuzzleInput::parse_lines`](crate::PuzzleInput::parse_lines).
+///
+/// Errors are reported as if the line was the first line of the input,
+/// `parse_lines` moves them to the actual line.
+pub trait FromPuzzleLine: Sized {
+    fn from_line(line: &str) -> Result<Self, Error>;
+}
+
+/// Part of a line matched by a `{}` placeholder of a pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capture<'a> {
+    pub text: &'a str,
+    /// 1-based column of the first character of the capture.
+    pub column: usize,
+}
+
+impl Capture<'_> {
+    /// Converts the captured text into the requested type.
+    pub fn parse<T>(&self) -> Result<T, Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text.parse::<T>().map_err(|e| Error::Parse {
+            line: 1,
+            column: self.column,
+            token: self.text.to_string(),
+            message: e.to_string(),
+        })
+    }
+}
+
+/// Matches a line against a pattern like `{},{} -> {},{}` and returns the text of every
+/// `{}` placeholder. A placeholder extends up to the first occurrence of the text following it,
+/// the last one up to the end of the line if nothing follows it.
+///
+/// Panics if two placeholders directly follow each other, as the line can't be split between them.
+pub fn captures<'a>(pattern: &str, line: &'a str) -> Result<Vec<Capture<'a>>, Error> {
+    let mut literals = pattern.split("{}");
+    let mut rest = line;
+    let mut captures = vec![];
+
+    let column = |rest: &str| line[..line.len() - rest.len()].chars().count() + 1;
+    let expected = |literal: &str, rest: &str| Error::Parse {
+        line: 1,
+        column: column(rest),
+        token: rest.to_string(),
+        message: format!("expected {:?}", literal),
+    };
+
+    let prefix = literals.next().unwrap_or_default();
+    rest = rest
+        .strip_prefix(prefix)
+        .ok_or_else(|| expected(prefix, rest))?;
+
+    let literals = literals.collect::<Vec<_>>();
+    for (i, literal) in literals.iter().enumerate() {
+        let is_last = i == literals.len() - 1;
+        let end = if is_last && literal.is_empty() {
+            rest.len()
+        } else {
+            assert!(
+                !literal.is_empty(),
+                "Placeholders of pattern {:?} need to be separated",
+                pattern
+            );
+            rest.find(literal).ok_or_else(|| expected(literal, rest))?
+        };
+
+        captures.push(Capture {
+            text: &rest[..end],
+            column: column(rest),
+        });
+        rest = &rest[end + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(Error::Parse {
+            line: 1,
+            column: column(rest),
+            token: rest.to_string(),
+            message: "unexpected text at the end of the line".to_string(),
+        });
+    }
+
+    Ok(captures)
+}
+
+/// Implements [`FromPuzzleLine`] by matching lines against a pattern, see [`captures`].
+/// Every `{}` of the pattern is converted into the type of the corresponding closure parameter
+/// using [`FromStr`], and the closure body builds the value from them:
+///
+/// ```
+/// use aoc_utils::{from_puzzle_line, FromPuzzleLine};
+///
+/// struct Line {
+///     start: (i32, i32),
+///     end: (i32, i32),
+/// }
+///
+/// from_puzzle_line!(Line, "{},{} -> {},{}", |x1: i32, y1: i32, x2: i32, y2: i32| Line {
+///     start: (x1, y1),
+///     end: (x2, y2),
+/// });
+///
+/// let line = Line::from_line("0,9 -> 5,9").unwrap();
+/// assert_eq!((line.start, line.end), ((0, 9), (5, 9)));
+/// ```
+#[macro_export]
+macro_rules! from_puzzle_line {
+    ($target:ty, $pattern:expr, |$($name:ident : $field:ty),* $(,)?| $body:expr $(,)?) => {
+        impl $crate::FromPuzzleLine for $target {
+            fn from_line(line: &str) -> Result<Self, $crate::Error> {
+                let captures = $crate::pattern::captures($pattern, line)?;
+                let names: &[&str] = &[$(stringify!($name)),*];
+                assert_eq!(
+                    captures.len(),
+                    names.len(),
+                    "Pattern {:?} doesn't have a placeholder for every value of {:?}",
+                    $pattern,
+                    names
+                );
+
+                let mut captures = captures.into_iter();
+                $(let $name: $field = captures.next().unwrap().parse()?;)*
+                Ok($body)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts<'a>(pattern: &str, line: &'a str) -> Vec<&'a str> {
+        captures(pattern, line)
+            .unwrap()
+            .iter()
+            .map(|c| c.text)
+            .collect()
+    }
+
+    #[test]
+    fn test_captures() {
+        assert_eq!(
+            texts("{},{} -> {},{}", "0,9 -> 5,9"),
+            vec!["0", "9", "5", "9"]
+        );
+        assert_eq!(texts("{} {}", "forward 5"), vec!["forward", "5"]);
+        assert_eq!(
+            texts(
+                "target area: x={}..{}, y={}..{}",
+                "target area: x=20..30, y=-10..-5"
+            ),
+            vec!["20", "30", "-10", "-5"]
+        );
+        assert_eq!(texts("<{}>", "<a>"), vec!["a"]);
+        assert_eq!(texts("", ""), Vec::<&str>::new());
+
+        let columns = captures("{} x={}", "on x=-5")
+            .unwrap()
+            .iter()
+            .map(|c| c.column)
+            .collect::<Vec<_>>();
+        assert_eq!(columns, vec![1, 6]);
+    }
+
+    #[test]
+    fn test_captures_errors() {
+        let error = captures("{},{} -> {},{}", "0,9 => 5,9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"9 => 5,9\" at line 1, column 3: expected \" -> \""
+        );
+        let error = captures("<{}>", "<a>b").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"b\" at line 1, column 4: unexpected text at the end of the line"
+        );
+        assert!(captures("x={}", "y=1").is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_captures_rejects_adjacent_placeholders() {
+        let _ = captures("{}{}", "12");
+    }
+
+    struct Move {
+        direction: char,
+        steps: u8,
+    }
+
+    from_puzzle_line!(Move, "{} {}", |direction: char, steps: u8| Move {
+        direction,
+        steps
+    });
+
+    #[test]
+    fn test_from_puzzle_line() {
+        let step = Move::from_line("R 12").unwrap();
+        assert_eq!((step.direction, step.steps), ('R', 12));
+
+        match Move::from_line("R 300") {
+            Err(Error::Parse { column, token, .. }) => {
+                assert_eq!(column, 3);
+                assert_eq!(token, "300");
+            }
+            _ => panic!("Expected a parse error"),
+        }
+    }
+}
@@ -0,0 +1,325 @@
+use crate::{Answer, PuzzleInput, DEFAULT_YEAR};
+use std::any::{Any, TypeId};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// The solution of a single day, split into parsing the input and solving both parts.
+pub trait Solution {
+    /// Year of the puzzle, used with the day to find the puzzle input.
+    const YEAR: u16 = DEFAULT_YEAR;
+    /// Day of the puzzle, used to find the puzzle input.
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input, shared by both parts.
+    type Input;
+    type AnswerA: Into<Answer>;
+    /// Use [`NoAnswer`] for days that only have a single part.
+    type AnswerB: Into<Answer>;
+
+    fn parse(input: &PuzzleInput) -> Self::Input;
+    fn solve_a(input: &Self::Input) -> Self::AnswerA;
+    fn solve_b(input: &Self::Input) -> Self::AnswerB;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Part::A => "A",
+            Part::B => "B",
+        })
+    }
+}
+
+/// Answer of a part that doesn't exist, e.g. part B of day 25.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Parsed input of a [`RegisteredSolution`], can only be used with the solution that created it.
+pub type ParsedInput = Box<dyn Any>;
+
+/// A [`Solution`] with its types erased, so that solutions of different days can be stored together.
+pub struct RegisteredSolution {
+    year: u16,
+    day: u8,
+    has_part_b: bool,
+    parse: fn(&PuzzleInput) -> ParsedInput,
+    solve_a: fn(&ParsedInput) -> (Answer, Duration),
+    solve_b: fn(&ParsedInput) -> (Answer, Duration),
+}
+
+impl RegisteredSolution {
+    fn new<S>() -> RegisteredSolution
+    where
+        S: Solution,
+        S::Input: 'static,
+        S::AnswerB: 'static,
+    {
+        RegisteredSolution {
+            year: S::YEAR,
+            day: S::DAY,
+            has_part_b: TypeId::of::<S::AnswerB>() != TypeId::of::<NoAnswer>(),
+            parse: |input| Box::new(S::parse(input)),
+            solve_a: |parsed| Self::timed(|| S::solve_a(Self::downcast::<S>(parsed))),
+            solve_b: |parsed| Self::timed(|| S::solve_b(Self::downcast::<S>(parsed))),
+        }
+    }
+
+    /// Converting the answer happens after the measurement.
+    fn timed<T: Into<Answer>>(solve: impl FnOnce() -> T) -> (Answer, Duration) {
+        let start = Instant::now();
+        let answer = solve();
+        let time = start.elapsed();
+        (answer.into(), time)
+    }
+
+    fn downcast<S: Solution>(parsed: &ParsedInput) -> &S::Input
+    where
+        S::Input: 'static,
+    {
+        parsed
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("Parsed input does not belong to day {}", S::DAY))
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn has_part_b(&self) -> bool {
+        self.has_part_b
+    }
+
+    pub fn parse(&self, input: &PuzzleInput) -> ParsedInput {
+        (self.parse)(input)
+    }
+
+    pub fn solve_a(&self, parsed: &ParsedInput) -> Answer {
+        self.time_a(parsed).0
+    }
+
+    /// Returns `None` if this day has no part B.
+    pub fn solve_b(&self, parsed: &ParsedInput) -> Option<Answer> {
+        self.time_b(parsed).map(|(answer, _)| answer)
+    }
+
+    /// Solves part A and measures how long solving took, without converting the answer.
+    pub fn time_a(&self, parsed: &ParsedInput) -> (Answer, Duration) {
+        (self.solve_a)(parsed)
+    }
+
+    /// Like [`RegisteredSolution::time_a`], returns `None` if this day has no part B.
+    pub fn time_b(&self, parsed: &ParsedInput) -> Option<(Answer, Duration)> {
+        if self.has_part_b {
+            Some((self.solve_b)(parsed))
+        } else {
+            None
+        }
+    }
+}
+
+/// Collection of the solutions of all days, ordered by year and day.
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), RegisteredSolution>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Adds the solution `S`. Panics if another solution was already registered for its year and day.
+    pub fn register<S>(&mut self)
+    where
+        S: Solution,
+        S::Input: 'static,
+        S::AnswerB: 'static,
+    {
+        if self.solutions.contains_key(&(S::YEAR, S::DAY)) {
+            panic!(
+                "A solution for day {} of {} is already registered",
+                S::DAY,
+                S::YEAR
+            );
+        }
+        self.solutions
+            .insert((S::YEAR, S::DAY), RegisteredSolution::new::<S>());
+    }
+
+    /// Solution of the given day of [`DEFAULT_YEAR`].
+    pub fn get(&self, day: u8) -> Option<&RegisteredSolution> {
+        self.get_for(DEFAULT_YEAR, day)
+    }
+
+    pub fn get_for(&self, year: u16, day: u8) -> Option<&RegisteredSolution> {
+        self.solutions.get(&(year, day))
+    }
+
+    /// Days of [`DEFAULT_YEAR`] that have a solution.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.days_of(DEFAULT_YEAR)
+    }
+
+    pub fn days_of(&self, year: u16) -> impl Iterator<Item = u8> + '_ {
+        self.solutions
+            .keys()
+            .filter(move |(y, _)| *y == year)
+            .map(|&(_, day)| day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &RegisteredSolution> {
+        self.solutions.values()
+    }
+}
+
+/// Entry point for the day binaries: reads the input, solves both parts and prints the answers.
+pub fn run<S>()
+where
+    S: Solution,
+    S::Input: 'static,
+    S::AnswerB: 'static,
+{
+    let solution = RegisteredSolution::new::<S>();
+    let input = PuzzleInput::from_args_for(S::YEAR, S::DAY);
+    let parsed = solution.parse(&input);
+    print_answer(Part::A, &solution.solve_a(&parsed));
+    if let Some(answer_b) = solution.solve_b(&parsed) {
+        print_answer(Part::B, &answer_b);
+    }
+}
+
+/// Prints the answer after the part, answers spanning multiple lines start on their own line.
+fn print_answer(part: Part, answer: &Answer) {
+    if answer.is_multiline() {
+        println!("{}:\n{}", part, answer);
+    } else {
+        println!("{}: {}", part, answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 0;
+        type Input = Vec<String>;
+        type AnswerA = usize;
+        type AnswerB = String;
+
+        fn parse(input: &PuzzleInput) -> Vec<String> {
+            input.lines()
+        }
+
+        fn solve_a(lines: &Vec<String>) -> usize {
+            lines.len()
+        }
+
+        fn solve_b(lines: &Vec<String>) -> String {
+            lines.concat()
+        }
+    }
+
+    struct SinglePart;
+
+    impl Solution for SinglePart {
+        const DAY: u8 = 25;
+        type Input = ();
+        type AnswerA = u8;
+        type AnswerB = NoAnswer;
+
+        fn parse(_: &PuzzleInput) {}
+
+        fn solve_a(_: &()) -> u8 {
+            42
+        }
+
+        fn solve_b(_: &()) -> NoAnswer {
+            NoAnswer
+        }
+    }
+
+    #[test]
+    fn test_registry_runs_erased_solutions() {
+        let mut registry = Registry::new();
+        registry.register::<SinglePart>();
+        registry.register::<Lines>();
+        assert_eq!(registry.days().collect::<Vec<_>>(), vec![0, 25]);
+
+        let lines = registry.get(0).unwrap();
+        let parsed = lines.parse(&PuzzleInput::new("a\nb"));
+        assert_eq!(lines.solve_a(&parsed), Answer::Integer(2));
+        assert_eq!(lines.solve_b(&parsed), Some(Answer::from("ab")));
+
+        let single_part = registry.get(25).unwrap();
+        let parsed = single_part.parse(&PuzzleInput::new(""));
+        assert_eq!(single_part.solve_a(&parsed), Answer::Integer(42));
+        assert_eq!(single_part.solve_b(&parsed), None);
+    }
+
+    struct NextYear;
+
+    impl Solution for NextYear {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 25;
+        type Input = ();
+        type AnswerA = u8;
+        type AnswerB = NoAnswer;
+
+        fn parse(_: &PuzzleInput) {}
+
+        fn solve_a(_: &()) -> u8 {
+            22
+        }
+
+        fn solve_b(_: &()) -> NoAnswer {
+            NoAnswer
+        }
+    }
+
+    #[test]
+    fn test_registry_separates_years() {
+        let mut registry = Registry::new();
+        registry.register::<SinglePart>();
+        registry.register::<NextYear>();
+        assert_eq!(registry.days().collect::<Vec<_>>(), vec![25]);
+        assert_eq!(registry.days_of(2022).collect::<Vec<_>>(), vec![25]);
+
+        let next_year = registry.get_for(2022, 25).unwrap();
+        assert_eq!(next_year.year(), 2022);
+        assert_eq!(
+            next_year.solve_a(&next_year.parse(&PuzzleInput::new(""))),
+            Answer::Integer(22)
+        );
+        assert_eq!(registry.get(25).unwrap().year(), DEFAULT_YEAR);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_registry_rejects_duplicate_day() {
+        let mut registry = Registry::new();
+        registry.register::<Lines>();
+        registry.register::<Lines>();
+    }
+}
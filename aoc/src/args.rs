@@ -0,0 +1,241 @@
+use aoc_utils::Part;
+
+pub const USAGE: &str = "Usage: aoc run <days> [--part a|b]
+       aoc bench <days> [--part a|b] [--iterations n] [--json file]
+       aoc fetch <days>
+       aoc readme [--test]
+
+<days> is a single day (5), a range (1..=25 or 1..26) or all.
+bench writes its results as JSON to the given file, or to stdout if it is -.
+fetch downloads the inputs that don't exist yet, using the session token from AOC_SESSION
+or ~/.config/aoc/session.
+readme runs all days and updates the badges and the table of days in README.md,
+with --test only days whose tests pass are counted.";
+
+const DEFAULT_ITERATIONS: usize = 10;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    /// Runs the given days. `part` is `None` if both parts should be run.
+    Run { days: Vec<u8>, part: Option<Part> },
+    /// Times parsing and the selected parts of the given days over multiple iterations.
+    Bench {
+        days: Vec<u8>,
+        part: Option<Part>,
+        iterations: usize,
+        json: Option<String>,
+    },
+    /// Downloads the inputs of the given days.
+    Fetch { days: Vec<u8> },
+    /// Updates the progress shown in the README, optionally running the tests of each day.
+    Readme { test: bool },
+}
+
+/// Parses the command line arguments, without the program name.
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+    let command = match args.next().map(|s| s.as_str()) {
+        Some("readme") => return parse_readme_args(args),
+        Some(command @ ("run" | "bench" | "fetch")) => command,
+        Some(command) => return Err(format!("Unknown command \"{}\"", command)),
+        None => return Err("Missing command".to_string()),
+    };
+    let is_bench = command == "bench";
+    let is_fetch = command == "fetch";
+
+    let mut days = None;
+    let mut part = None;
+    let mut iterations = DEFAULT_ITERATIONS;
+    let mut json = None;
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("Missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--part" | "-p" if !is_fetch => part = Some(parse_part(value()?)?),
+            "--iterations" | "-n" if is_bench => {
+                iterations = match value()?.parse::<usize>() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err("--iterations has to be a positive number".to_string()),
+                }
+            }
+            "--json" if is_bench => json = Some(value()?.clone()),
+            _ if days.is_none() => days = Some(parse_days(arg)?),
+            _ => return Err(format!("Unexpected argument \"{}\"", arg)),
+        }
+    }
+
+    let days = days.ok_or_else(|| format!("Missing days to {}", command))?;
+    Ok(if is_fetch {
+        Command::Fetch { days }
+    } else if is_bench {
+        Command::Bench {
+            days,
+            part,
+            iterations,
+            json,
+        }
+    } else {
+        Command::Run { days, part }
+    })
+}
+
+fn parse_readme_args<'a>(args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut test = false;
+    for arg in args {
+        match arg.as_str() {
+            "--test" => test = true,
+            _ => return Err(format!("Unexpected argument \"{}\"", arg)),
+        }
+    }
+    Ok(Command::Readme { test })
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "a" => Ok(Part::A),
+        "b" => Ok(Part::B),
+        _ => Err(format!("Invalid part \"{}\", expected a or b", s)),
+    }
+}
+
+/// Parses `all`, a single day or a range of days with either an inclusive (`..=`)
+/// or exclusive (`..`) end.
+fn parse_days(s: &str) -> Result<Vec<u8>, String> {
+    if s == "all" {
+        return Ok((1..=25).collect());
+    }
+
+    let (start, end) = if let Some((start, end)) = s.split_once("..=") {
+        (parse_day(start, 25)?, parse_day(end, 25)?)
+    } else if let Some((start, end)) = s.split_once("..") {
+        (parse_day(start, 25)?, parse_day(end, 26)? - 1)
+    } else {
+        let day = parse_day(s, 25)?;
+        (day, day)
+    };
+
+    if start > end {
+        return Err(format!("Empty range of days \"{}\"", s));
+    }
+    Ok((start..=end).collect())
+}
+
+fn parse_day(s: &str, max: u8) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if (1..=max).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Invalid day \"{}\", expected a number from 1 to {}",
+            s, max
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_run() {
+        assert_eq!(
+            parse_args(&args("run 5")),
+            Ok(Command::Run {
+                days: vec![5],
+                part: None
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run 1..=25 --part b")),
+            Ok(Command::Run {
+                days: (1..=25).collect(),
+                part: Some(Part::B)
+            })
+        );
+        assert_eq!(
+            parse_args(&args("run --part A all")),
+            Ok(Command::Run {
+                days: (1..=25).collect(),
+                part: Some(Part::A)
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_bench() {
+        assert_eq!(
+            parse_args(&args("bench 15")),
+            Ok(Command::Bench {
+                days: vec![15],
+                part: None,
+                iterations: 10,
+                json: None
+            })
+        );
+        assert_eq!(
+            parse_args(&args("bench all -n 3 --json bench.json --part a")),
+            Ok(Command::Bench {
+                days: (1..=25).collect(),
+                part: Some(Part::A),
+                iterations: 3,
+                json: Some("bench.json".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_fetch() {
+        assert_eq!(
+            parse_args(&args("fetch 1..=3")),
+            Ok(Command::Fetch {
+                days: vec![1, 2, 3]
+            })
+        );
+        assert!(parse_args(&args("fetch 1 --part a")).is_err());
+        assert!(parse_args(&args("fetch")).is_err());
+    }
+
+    #[test]
+    fn test_parse_readme() {
+        assert_eq!(
+            parse_args(&args("readme")),
+            Ok(Command::Readme { test: false })
+        );
+        assert_eq!(
+            parse_args(&args("readme --test")),
+            Ok(Command::Readme { test: true })
+        );
+        assert!(parse_args(&args("readme 5")).is_err());
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("3..6"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("24..=25"), Ok(vec![24, 25]));
+        assert_eq!(parse_days("1..26"), Ok((1..=25).collect()));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("6..=3").is_err());
+        assert!(parse_days("x").is_err());
+        assert_eq!(
+            parse_days("3..27"),
+            Err("Invalid day \"27\", expected a number from 1 to 26".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_args() {
+        assert!(parse_args(&args("")).is_err());
+        assert!(parse_args(&args("test 5")).is_err());
+        assert!(parse_args(&args("run")).is_err());
+        assert!(parse_args(&args("run 5 --part c")).is_err());
+        assert!(parse_args(&args("run 5 6")).is_err());
+        assert!(parse_args(&args("run 5 --iterations 3")).is_err());
+        assert!(parse_args(&args("bench 5 --iterations 0")).is_err());
+        assert!(parse_args(&args("bench 5 --json")).is_err());
+    }
+}
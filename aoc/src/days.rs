@@ -0,0 +1,41 @@
+use aoc_utils::Registry;
+
+/// Registry containing the solutions of all days.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register::<day01::Day01>();
+    registry.register::<day02::Day02>();
+    registry.register::<day03::Day03>();
+    registry.register::<day04::Day04>();
+    registry.register::<day05::Day05>();
+    registry.register::<day06::Day06>();
+    registry.register::<day07::Day07>();
+    registry.register::<day08::Day08>();
+    registry.register::<day09::Day09>();
+    registry.register::<day10::Day10>();
+    registry.register::<day11::Day11>();
+    registry.register::<day12::Day12>();
+    registry.register::<day13::Day13>();
+    registry.register::<day14::Day14>();
+    registry.register::<day15::Day15>();
+    registry.register::<day16::Day16>();
+    registry.register::<day17::Day17>();
+    registry.register::<day18::Day18>();
+    registry.register::<day19::Day19>();
+    registry.register::<day20::Day20>();
+    registry.register::<day21::Day21>();
+    registry.register::<day22::Day22>();
+    registry.register::<day23::Day23>();
+    registry.register::<day24::Day24>();
+    registry.register::<day25::Day25>();
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_all_days_registered() {
+        let days = super::registry().days().collect::<Vec<_>>();
+        assert_eq!(days, (1..=25).collect::<Vec<_>>());
+    }
+}
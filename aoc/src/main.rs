@@ -0,0 +1,277 @@
+mod args;
+mod days;
+mod readme;
+mod report;
+
+use aoc_utils::fetch::Fetcher;
+use aoc_utils::{
+    bench, day_path, input_file_name, Answers, Part, PuzzleInput, Registry, Verdict, DEFAULT_YEAR,
+};
+use args::Command;
+use readme::DayStatus;
+use report::{DayResult, PartResult};
+use std::fs;
+use std::path::Path;
+use std::process::{self, exit};
+use std::time::Instant;
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let command = match args::parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, args::USAGE);
+            exit(2);
+        }
+    };
+
+    let success = match command {
+        Command::Run { days, part } => run(&days, part),
+        Command::Bench {
+            days,
+            part,
+            iterations,
+            json,
+        } => bench(&days, part, iterations, json.as_deref()),
+        Command::Fetch { days } => fetch(&days),
+        Command::Readme { test } => update_readme(test),
+    };
+    if !success {
+        exit(1);
+    }
+}
+
+/// Runs the days, prints their answers and checks them against answers.toml.
+/// Returns whether all days could be run and had the expected answers.
+fn run(days: &[u8], part: Option<Part>) -> bool {
+    let registry = days::registry();
+    let answers = match Answers::load() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+    if answers.is_none() {
+        eprintln!(
+            "No {} found, answers are not checked",
+            aoc_utils::ANSWERS_FILE
+        );
+    }
+
+    let mut results = vec![];
+    let mut success = true;
+    for &day in days {
+        match run_day(&registry, day, part, answers.as_ref()) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                success = false;
+            }
+        }
+    }
+
+    print!("{}", report::render_table(&results));
+    for part in results.iter().flat_map(|r| &r.parts) {
+        if let Some(Verdict::Wrong(mismatch)) = &part.verdict {
+            eprintln!("\n{}", mismatch);
+            success = false;
+        }
+    }
+    success
+}
+
+/// Benchmarks the days and prints the timings, optionally also writing them as JSON.
+/// Returns whether all days could be benchmarked.
+fn bench(days: &[u8], part: Option<Part>, iterations: usize, json: Option<&str>) -> bool {
+    let registry = days::registry();
+    let mut results = vec![];
+    let mut success = true;
+    for &day in days {
+        let solution = registry.get(day);
+        match (solution, PuzzleInput::try_get_input(day)) {
+            (Some(solution), Ok(input)) => {
+                results.push(bench::bench(solution, &input, iterations, part))
+            }
+            (None, _) => {
+                eprintln!("Day {}: No solution registered", day);
+                success = false;
+            }
+            (_, Err(e)) => {
+                eprintln!("Day {}: {}", day, e);
+                success = false;
+            }
+        }
+    }
+
+    let json_output = bench::to_json(&results);
+    match json {
+        Some("-") => print!("{}", json_output),
+        Some(path) => {
+            print!("{}", report::render_bench_table(&results));
+            if let Err(e) = fs::write(path, json_output) {
+                eprintln!("Unable to write {}: {}", path, e);
+                success = false;
+            }
+        }
+        None => print!("{}", report::render_bench_table(&results)),
+    }
+    success
+}
+
+/// Downloads the inputs of the days that don't have one yet into their day directories.
+/// Returns whether all inputs exist afterwards.
+fn fetch(days: &[u8]) -> bool {
+    let mut fetcher = match Fetcher::from_env() {
+        Ok(fetcher) => fetcher,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut success = true;
+    for &day in days {
+        let path = day_path(DEFAULT_YEAR, day).join(input_file_name(day));
+        if path.exists() {
+            println!("Day {}: {} already exists", day, path.display());
+            continue;
+        }
+        match fetcher.fetch_to(Path::new("."), DEFAULT_YEAR, day) {
+            Ok(path) => println!("Day {}: Fetched {}", day, path.display()),
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+/// Runs all days and rewrites the badges and the table of days in the README with their progress.
+/// A day counts as completed if all of its parts have the answers recorded in answers.toml,
+/// and if `test` is set, its tests also have to pass. Returns whether the README could be updated.
+fn update_readme(test: bool) -> bool {
+    let registry = days::registry();
+    let answers = match Answers::load() {
+        Ok(Some(answers)) => answers,
+        Ok(None) => {
+            eprintln!("No {} found", aoc_utils::ANSWERS_FILE);
+            return false;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut statuses = vec![];
+    for day in 1..=25 {
+        let mut status = match run_day(&registry, day, None, Some(&answers)) {
+            Ok(result) => DayStatus::from_result(&result, &answers),
+            Err(_) => DayStatus::unsolved(day, &answers),
+        };
+        if test && status.completed && !run_tests(day) {
+            eprintln!("Day {}: Tests failed", day);
+            status.completed = false;
+            status.stars = 0;
+        }
+        statuses.push(status);
+    }
+    readme::award_last_star(&mut statuses);
+
+    let updated = fs::read_to_string(readme::README_FILE)
+        .map_err(|e| format!("Unable to read {}: {}", readme::README_FILE, e))
+        .and_then(|content| {
+            readme::replace_between(
+                &content,
+                readme::BADGES_START,
+                readme::BADGES_END,
+                &readme::render_badges(&statuses),
+            )
+        })
+        .and_then(|content| {
+            readme::replace_between(
+                &content,
+                readme::DAYS_START,
+                readme::DAYS_END,
+                &readme::render_days_table(&statuses),
+            )
+        })
+        .and_then(|content| {
+            fs::write(readme::README_FILE, content)
+                .map_err(|e| format!("Unable to write {}: {}", readme::README_FILE, e))
+        });
+    match updated {
+        Ok(()) => {
+            println!(
+                "Updated {}: {} days completed, {} stars",
+                readme::README_FILE,
+                statuses.iter().filter(|s| s.completed).count(),
+                statuses.iter().map(|s| s.stars).sum::<usize>()
+            );
+            true
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            false
+        }
+    }
+}
+
+/// Runs the tests of a day with cargo, returns whether they passed.
+fn run_tests(day: u8) -> bool {
+    process::Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["test", "--quiet", "--release", "-p"])
+        .arg(aoc_utils::package_name(DEFAULT_YEAR, day))
+        .stdout(process::Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Parses the input of a day and runs the selected parts on it.
+/// The answers are checked if there are recorded answers.
+fn run_day(
+    registry: &Registry,
+    day: u8,
+    part: Option<Part>,
+    answers: Option<&Answers>,
+) -> Result<DayResult, String> {
+    let solution = registry
+        .get(day)
+        .ok_or_else(|| "No solution registered".to_string())?;
+    let input = PuzzleInput::try_get_input(day).map_err(|e| e.to_string())?;
+
+    let start = Instant::now();
+    let parsed = solution.parse(&input);
+    let parse_time = start.elapsed();
+
+    let mut parts = vec![];
+    if part != Some(Part::B) {
+        let (answer, time) = solution.time_a(&parsed);
+        parts.push(PartResult {
+            part: Part::A,
+            verdict: answers.map(|answers| answers.check(day, Part::A, &answer.to_string())),
+            answer,
+            time,
+        });
+    }
+
+    if part != Some(Part::A) {
+        if let Some((answer, time)) = solution.time_b(&parsed) {
+            parts.push(PartResult {
+                part: Part::B,
+                verdict: answers.map(|answers| answers.check(day, Part::B, &answer.to_string())),
+                answer,
+                time,
+            });
+        }
+    }
+
+    Ok(DayResult {
+        day,
+        parse_time,
+        parts,
+    })
+}
@@ -0,0 +1,196 @@
+use crate::report::{format_duration, DayResult};
+use aoc_utils::{day_path, Answers, Verdict, DEFAULT_YEAR};
+use std::fmt::Write;
+use std::time::Duration;
+
+pub const README_FILE: &str = "README.md";
+
+pub const BADGES_START: &str = "<!-- badges:start -->";
+pub const BADGES_END: &str = "<!-- badges:end -->";
+pub const DAYS_START: &str = "<!-- days:start -->";
+pub const DAYS_END: &str = "<!-- days:end -->";
+
+/// Progress of a single day, as shown in the README.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    pub title: Option<String>,
+    /// Number of parts whose answer matches the recorded one.
+    pub stars: usize,
+    /// Whether all parts of the day have the recorded answers (and its tests pass, if they were run).
+    pub completed: bool,
+    /// Time needed to parse the input and solve both parts, `None` if the day couldn't be run.
+    pub time: Option<Duration>,
+}
+
+impl DayStatus {
+    /// Status of a day that could be run, see [`DayStatus::unsolved`] for the other days.
+    pub fn from_result(result: &DayResult, answers: &Answers) -> DayStatus {
+        let stars = result
+            .parts
+            .iter()
+            .filter(|p| p.verdict == Some(Verdict::Correct))
+            .count();
+        DayStatus {
+            day: result.day,
+            title: answers.title(result.day).map(|t| t.to_string()),
+            stars,
+            completed: stars > 0 && stars == result.parts.len(),
+            time: Some(result.parse_time + result.parts.iter().map(|p| p.time).sum::<Duration>()),
+        }
+    }
+
+    /// Status of a day without a solution or input.
+    pub fn unsolved(day: u8, answers: &Answers) -> DayStatus {
+        DayStatus {
+            day,
+            title: answers.title(day).map(|t| t.to_string()),
+            stars: 0,
+            completed: false,
+            time: None,
+        }
+    }
+}
+
+/// Day 25 only has a single puzzle, its second star is awarded for having all other stars.
+pub fn award_last_star(statuses: &mut [DayStatus]) {
+    let others_complete = (1..25).all(|day| {
+        statuses
+            .iter()
+            .any(|s| s.day == day && s.stars == 2 && s.completed)
+    });
+    if let Some(last) = statuses.iter_mut().find(|s| s.day == 25) {
+        if others_complete && last.completed && last.stars == 1 {
+            last.stars = 2;
+        }
+    }
+}
+
+/// Renders the badges with the number of completed days and collected stars.
+pub fn render_badges(statuses: &[DayStatus]) -> String {
+    let completed = statuses.iter().filter(|s| s.completed).count();
+    let stars = statuses.iter().map(|s| s.stars).sum::<usize>();
+    format!(
+        "[![Days completed](https://img.shields.io/badge/day%20📅-{completed}-blue)](https://adventofcode.com/{year})\n\
+         [![Stars](https://img.shields.io/badge/stars%20⭐-{stars}-yellow)](https://adventofcode.com/{year}/stats)",
+        completed = completed,
+        stars = stars,
+        year = DEFAULT_YEAR
+    )
+}
+
+/// Renders a markdown table with one row per day, linking the puzzle and the solution.
+pub fn render_days_table(statuses: &[DayStatus]) -> String {
+    let mut table = String::new();
+    table.push_str("| Day | Title | Stars | Time | Solution |\n");
+    table.push_str("|----:|-------|-------|-----:|----------|\n");
+    for status in statuses {
+        let solution = day_path(DEFAULT_YEAR, status.day);
+        let _ = writeln!(
+            table,
+            "| [{day}](https://adventofcode.com/{year}/day/{day}) | {title} | {stars} | {time} | {solution} |",
+            day = status.day,
+            year = DEFAULT_YEAR,
+            title = status.title.as_deref().unwrap_or_default(),
+            stars = "⭐".repeat(status.stars),
+            time = status.time.map(format_duration).unwrap_or_default(),
+            solution = match status.time {
+                Some(_) => format!(
+                    "[{}]({}/src/lib.rs)",
+                    solution.display(),
+                    solution.display()
+                ),
+                None => String::new(),
+            }
+        );
+    }
+    table
+}
+
+/// Replaces everything between the start and end marker with `replacement`, keeping the markers.
+pub fn replace_between(
+    content: &str,
+    start_marker: &str,
+    end_marker: &str,
+    replacement: &str,
+) -> Result<String, String> {
+    let start = content
+        .find(start_marker)
+        .ok_or_else(|| format!("Missing marker {}", start_marker))?
+        + start_marker.len();
+    let end = content[start..]
+        .find(end_marker)
+        .ok_or_else(|| format!("Missing marker {} after {}", end_marker, start_marker))?
+        + start;
+
+    Ok(format!(
+        "{}\n{}\n{}",
+        &content[..start],
+        replacement.trim_end(),
+        &content[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(day: u8, stars: usize, time: Option<u64>) -> DayStatus {
+        DayStatus {
+            day,
+            title: Some(format!("Title {}", day)),
+            stars,
+            completed: stars > 0,
+            time: time.map(Duration::from_micros),
+        }
+    }
+
+    #[test]
+    fn test_award_last_star() {
+        let mut statuses = (1..=24)
+            .map(|day| status(day, 2, Some(1)))
+            .chain([status(25, 1, Some(1))])
+            .collect::<Vec<_>>();
+        award_last_star(&mut statuses);
+        assert_eq!(statuses[24].stars, 2);
+
+        statuses[3] = status(4, 1, Some(1));
+        statuses[24].stars = 1;
+        award_last_star(&mut statuses);
+        assert_eq!(statuses[24].stars, 1);
+    }
+
+    #[test]
+    fn test_render() {
+        let statuses = vec![status(1, 2, Some(45)), status(2, 0, None)];
+        assert_eq!(
+            render_badges(&statuses),
+            "[![Days completed](https://img.shields.io/badge/day%20📅-1-blue)](https://adventofcode.com/2021)\n\
+             [![Stars](https://img.shields.io/badge/stars%20⭐-2-yellow)](https://adventofcode.com/2021/stats)"
+        );
+        assert_eq!(
+            render_days_table(&statuses),
+            "| Day | Title | Stars | Time | Solution |\n\
+             |----:|-------|-------|-----:|----------|\n\
+             | [1](https://adventofcode.com/2021/day/1) | Title 1 | ⭐⭐ | 45.0µs | [day01](day01/src/lib.rs) |\n\
+             | [2](https://adventofcode.com/2021/day/2) | Title 2 |  |  |  |\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_between() {
+        let readme = "# AoC\n<!-- days:start -->\nold\ntable\n<!-- days:end -->\nrest\n";
+        assert_eq!(
+            replace_between(readme, DAYS_START, DAYS_END, "new\n").unwrap(),
+            "# AoC\n<!-- days:start -->\nnew\n<!-- days:end -->\nrest\n"
+        );
+        assert!(replace_between(readme, BADGES_START, BADGES_END, "").is_err());
+        assert!(replace_between(
+            "<!-- days:end --><!-- days:start -->",
+            DAYS_START,
+            DAYS_END,
+            ""
+        )
+        .is_err());
+    }
+}
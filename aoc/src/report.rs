@@ -0,0 +1,222 @@
+use aoc_utils::bench::{BenchResult, Stats};
+use aoc_utils::{Answer, Part, Verdict};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Results of running the selected parts of a single day.
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub time: Duration,
+    /// Result of comparing the answer with answers.toml, `None` if it wasn't checked.
+    pub verdict: Option<Verdict>,
+}
+
+/// Formats a duration with a unit that keeps the number short, e.g. `12.3µs` or `4.56ms`.
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1}µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2}ms", micros / 1_000.0)
+    } else {
+        format!("{:.2}s", micros / 1_000_000.0)
+    }
+}
+
+/// Renders the results as a table with one row per part. The time needed to parse
+/// the input is shown once per day. Answers spanning multiple lines (e.g. the bitmap of day 13 B)
+/// continue on the following rows. The last column shows whether the answer matches
+/// the recorded one.
+pub fn render_table(results: &[DayResult]) -> String {
+    let answers = results
+        .iter()
+        .map(|r| {
+            r.parts
+                .iter()
+                .map(|p| p.answer.to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let answer_width = answers
+        .iter()
+        .flatten()
+        .flat_map(|answer| answer.lines())
+        .map(|line| line.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = String::new();
+    let _ = writeln!(
+        table,
+        "Day  Part  {:<width$}  {:>10}  {:>10}  Check",
+        "Answer",
+        "Parse",
+        "Time",
+        width = answer_width
+    );
+    for (result, answers) in results.iter().zip(&answers) {
+        for (i, (part, answer)) in result.parts.iter().zip(answers).enumerate() {
+            let parse_time = if i == 0 {
+                format_duration(result.parse_time)
+            } else {
+                String::new()
+            };
+            let mut lines = answer.lines();
+            let row = format!(
+                "{:>3}  {:<4}  {:<width$}  {:>10}  {:>10}  {}",
+                result.day,
+                part.part,
+                lines.next().unwrap_or_default(),
+                parse_time,
+                format_duration(part.time),
+                check_label(part.verdict.as_ref()),
+                width = answer_width
+            );
+            let _ = writeln!(table, "{}", row.trim_end());
+            for line in lines {
+                let _ = writeln!(table, "{:>11}{}", "", line);
+            }
+        }
+    }
+
+    let total: Duration = results
+        .iter()
+        .map(|r| r.parse_time + r.parts.iter().map(|p| p.time).sum::<Duration>())
+        .sum();
+    let _ = writeln!(
+        table,
+        "Total{:>width$}",
+        format_duration(total),
+        width = answer_width + 30
+    );
+    table
+}
+
+/// Renders the benchmark results as a table with one row per phase of each day.
+pub fn render_bench_table(results: &[BenchResult]) -> String {
+    let mut table = String::new();
+    let _ = writeln!(
+        table,
+        "Day  Phase  {:>10}  {:>10}  {:>10}",
+        "Min", "Median", "Max"
+    );
+    for result in results {
+        let phases = [
+            ("Parse", Some(result.parse)),
+            ("A", result.part_a),
+            ("B", result.part_b),
+        ];
+        for (phase, stats) in phases {
+            if let Some(Stats { min, median, max }) = stats {
+                let _ = writeln!(
+                    table,
+                    "{:>3}  {:<5}  {:>10}  {:>10}  {:>10}",
+                    result.day,
+                    phase,
+                    format_duration(min),
+                    format_duration(median),
+                    format_duration(max)
+                );
+            }
+        }
+    }
+    table
+}
+
+fn check_label(verdict: Option<&Verdict>) -> &'static str {
+    match verdict {
+        Some(Verdict::Correct) => "ok",
+        Some(Verdict::Wrong(_)) => "WRONG",
+        Some(Verdict::Unknown) => "?",
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::Grid;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_500)), "1.50s");
+    }
+
+    #[test]
+    fn test_render_bench_table() {
+        let stats = Stats {
+            min: Duration::from_micros(1),
+            median: Duration::from_micros(2),
+            max: Duration::from_micros(30),
+        };
+        let results = vec![BenchResult {
+            day: 25,
+            iterations: 3,
+            parse: stats,
+            part_a: Some(stats),
+            part_b: None,
+        }];
+        assert_eq!(
+            render_bench_table(&results),
+            "Day  Phase         Min      Median         Max\n\
+             \x2025  Parse       1.0µs       2.0µs      30.0µs\n\
+             \x2025  A           1.0µs       2.0µs      30.0µs\n"
+        );
+    }
+
+    #[test]
+    fn test_render_table() {
+        let results = vec![
+            DayResult {
+                day: 1,
+                parse_time: Duration::from_micros(5),
+                parts: vec![
+                    PartResult {
+                        part: Part::A,
+                        answer: Answer::Integer(7),
+                        time: Duration::from_micros(10),
+                        verdict: Some(Verdict::Correct),
+                    },
+                    PartResult {
+                        part: Part::B,
+                        answer: Answer::Bitmap(Grid::from_rows(vec![
+                            vec![true, false],
+                            vec![false, true],
+                        ])),
+                        time: Duration::from_micros(20),
+                        verdict: Some(Verdict::Unknown),
+                    },
+                ],
+            },
+            DayResult {
+                day: 25,
+                parse_time: Duration::from_micros(1),
+                parts: vec![PartResult {
+                    part: Part::A,
+                    answer: Answer::Integer(42),
+                    time: Duration::from_micros(2),
+                    verdict: None,
+                }],
+            },
+        ];
+        assert_eq!(
+            render_table(&results),
+            "Day  Part  Answer       Parse        Time  Check\n\
+             \x20 1  A     7            5.0µs      10.0µs  ok\n\
+             \x20 1  B     #.                      20.0µs  ?\n\
+             \x20          .#\n\
+             \x2025  A     42           1.0µs       2.0µs\n\
+             Total                              38.0µs\n"
+        );
+    }
+}
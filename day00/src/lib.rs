@@ -0,0 +1,47 @@
+use aoc_utils::{debug, NoAnswer, PuzzleInput, Solution};
+const DAY: u8 = 0;
+
+pub struct Day00;
+
+impl Solution for Day00 {
+    const DAY: u8 = DAY;
+    type Input = Vec<String>;
+    type AnswerA = usize;
+    type AnswerB = NoAnswer;
+
+    fn parse(input: &PuzzleInput) -> Vec<String> {
+        parse(input)
+    }
+
+    fn solve_a(lines: &Vec<String>) -> usize {
+        solve_a(lines)
+    }
+
+    fn solve_b(_: &Vec<String>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<String> {
+    input.lines()
+}
+
+pub fn solve_a(lines: &[String]) -> usize {
+    for line in lines {
+        debug!("{}", line);
+    }
+
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // solve_a should return 2
+    #[test]
+    fn test_solve_a() {
+        let input = PuzzleInput::get_input(DAY);
+        assert_eq!(solve_a(&input.lines()), 2);
+    }
+}
@@ -0,0 +1,75 @@
+pub mod sonar;
+
+use aoc_utils::{PuzzleInput, Solution};
+use sonar::{Aggregation, Trend};
+const DAY: u8 = 1;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = DAY;
+    type Input = Vec<i32>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> Vec<i32> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<i32>) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<i32>) -> usize {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<i32> {
+    input
+        .try_convert_to_ints_by_line::<i32>()
+        .expect("Invalid puzzle input")
+}
+
+/// Number of windows of the given size whose sum is larger than the one of the window before.
+fn count_increases(depths: &[i32], window_size: usize) -> usize {
+    let depths = depths.iter().map(|&depth| i64::from(depth));
+    sonar::changes(sonar::windows(depths, window_size, Aggregation::Sum))
+        .filter(|change| change.trend == Trend::Increasing)
+        .count()
+}
+
+pub fn solve_a(lines: &[i32]) -> usize {
+    count_increases(lines, 1)
+}
+
+pub fn solve_b(lines: &[i32]) -> usize {
+    count_increases(lines, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const EXAMPLE_INPUT: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        let input = parse(&PuzzleInput::new(EXAMPLE_INPUT.to_string()));
+        assert_eq!(solve_a(&input), 7);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        let input = parse(&PuzzleInput::new(EXAMPLE_INPUT.to_string()));
+        assert_eq!(solve_b(&input), 5);
+    }
+}
@@ -0,0 +1,375 @@
+use std::collections::VecDeque;
+
+/// How the depths of a window are combined into its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// How the value of a window compares to the one of the window before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trend {
+    Increasing,
+    Decreasing,
+    Flat,
+}
+
+/// The aggregated depths of a window.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Window {
+    /// Index of the first depth of the window.
+    pub start: usize,
+    pub value: f64,
+    /// Exact value the windows are compared by, means of equally sized windows compare like their sums.
+    key: i64,
+}
+
+/// A window compared with the window before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Change {
+    /// Start of the window that was compared with the one before it.
+    pub position: usize,
+    pub trend: Trend,
+}
+
+/// Consecutive changes with the same trend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub trend: Trend,
+    /// Position of the first change of the run.
+    pub start: usize,
+    /// Number of changes in the run.
+    pub length: usize,
+}
+
+/// Iterator over the windows of a sonar sweep, see [`windows`].
+pub struct Windows<I> {
+    depths: I,
+    size: usize,
+    aggregation: Aggregation,
+    index: usize,
+    /// Depths of the current window, only kept for sums and means.
+    depths_in_window: VecDeque<i64>,
+    sum: i64,
+    /// Candidates for the minimum or maximum of the current and following windows,
+    /// ordered by index with the extreme of the current window in front.
+    extremes: VecDeque<(usize, i64)>,
+}
+
+/// Aggregates every `size` consecutive depths, only the current window is kept in memory.
+/// Panics if `size` is 0.
+pub fn windows<I: IntoIterator<Item = i64>>(
+    depths: I,
+    size: usize,
+    aggregation: Aggregation,
+) -> Windows<I::IntoIter> {
+    assert!(size > 0, "Windows have to contain at least one depth");
+    Windows {
+        depths: depths.into_iter(),
+        size,
+        aggregation,
+        index: 0,
+        depths_in_window: VecDeque::with_capacity(size + 1),
+        sum: 0,
+        extremes: VecDeque::new(),
+    }
+}
+
+impl<I: Iterator<Item = i64>> Windows<I> {
+    fn push(&mut self, depth: i64) {
+        match self.aggregation {
+            Aggregation::Sum | Aggregation::Mean => {
+                self.sum += depth;
+                self.depths_in_window.push_back(depth);
+                if self.depths_in_window.len() > self.size {
+                    self.sum -= self.depths_in_window.pop_front().unwrap();
+                }
+            }
+            Aggregation::Min | Aggregation::Max => {
+                // Depths that can't be the extreme of any window anymore are dropped
+                while let Some(&(_, last)) = self.extremes.back() {
+                    let dominated = match self.aggregation {
+                        Aggregation::Min => last >= depth,
+                        _ => last <= depth,
+                    };
+                    if !dominated {
+                        break;
+                    }
+                    self.extremes.pop_back();
+                }
+                self.extremes.push_back((self.index, depth));
+                while self.extremes[0].0 + self.size <= self.index {
+                    self.extremes.pop_front();
+                }
+            }
+        }
+        self.index += 1;
+    }
+}
+
+impl<I: Iterator<Item = i64>> Iterator for Windows<I> {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        // The first window needs `size` depths, every further window one more
+        loop {
+            let depth = self.depths.next()?;
+            self.push(depth);
+            if self.index >= self.size {
+                break;
+            }
+        }
+
+        let (key, value) = match self.aggregation {
+            Aggregation::Sum => (self.sum, self.sum as f64),
+            Aggregation::Mean => (self.sum, self.sum as f64 / self.size as f64),
+            Aggregation::Min | Aggregation::Max => {
+                let extreme = self.extremes[0].1;
+                (extreme, extreme as f64)
+            }
+        };
+        Some(Window {
+            start: self.index - self.size,
+            value,
+            key,
+        })
+    }
+}
+
+/// Iterator over the changes between windows, see [`changes`].
+pub struct Changes<I> {
+    windows: I,
+    previous: Option<i64>,
+}
+
+/// Compares every window with the window before it.
+pub fn changes<I: IntoIterator<Item = Window>>(windows: I) -> Changes<I::IntoIter> {
+    Changes {
+        windows: windows.into_iter(),
+        previous: None,
+    }
+}
+
+impl<I: Iterator<Item = Window>> Iterator for Changes<I> {
+    type Item = Change;
+
+    fn next(&mut self) -> Option<Change> {
+        loop {
+            let window = self.windows.next()?;
+            if let Some(previous) = self.previous.replace(window.key) {
+                let trend = match window.key.cmp(&previous) {
+                    std::cmp::Ordering::Greater => Trend::Increasing,
+                    std::cmp::Ordering::Less => Trend::Decreasing,
+                    std::cmp::Ordering::Equal => Trend::Flat,
+                };
+                return Some(Change {
+                    position: window.start,
+                    trend,
+                });
+            }
+        }
+    }
+}
+
+/// Iterator over the runs of changes, see [`runs`].
+pub struct Runs<I> {
+    changes: I,
+    current: Option<Run>,
+}
+
+/// Groups consecutive changes with the same trend.
+pub fn runs<I: IntoIterator<Item = Change>>(changes: I) -> Runs<I::IntoIter> {
+    Runs {
+        changes: changes.into_iter(),
+        current: None,
+    }
+}
+
+impl<I: Iterator<Item = Change>> Iterator for Runs<I> {
+    type Item = Run;
+
+    fn next(&mut self) -> Option<Run> {
+        for change in self.changes.by_ref() {
+            match &mut self.current {
+                Some(run) if run.trend == change.trend => run.length += 1,
+                current => {
+                    let next = Run {
+                        trend: change.trend,
+                        start: change.position,
+                        length: 1,
+                    };
+                    if let Some(finished) = current.replace(next) {
+                        return Some(finished);
+                    }
+                }
+            }
+        }
+        self.current.take()
+    }
+}
+
+/// Statistics of the runs with one trend.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunStats {
+    /// Number of changes with this trend.
+    pub changes: usize,
+    pub runs: usize,
+    pub longest: Option<Run>,
+}
+
+impl RunStats {
+    fn add(&mut self, run: Run) {
+        self.changes += run.length;
+        self.runs += 1;
+        if self
+            .longest
+            .is_none_or(|longest| run.length > longest.length)
+        {
+            self.longest = Some(run);
+        }
+    }
+}
+
+/// Summary of a sonar sweep.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Report {
+    pub windows: usize,
+    pub increasing: RunStats,
+    pub decreasing: RunStats,
+    pub flat: RunStats,
+}
+
+impl Report {
+    pub fn stats(&self, trend: Trend) -> &RunStats {
+        match trend {
+            Trend::Increasing => &self.increasing,
+            Trend::Decreasing => &self.decreasing,
+            Trend::Flat => &self.flat,
+        }
+    }
+
+    /// The longest run of increases, the first one if there are multiple.
+    pub fn longest_climb(&self) -> Option<Run> {
+        self.increasing.longest
+    }
+}
+
+/// Aggregates the depths over windows of the given size and summarizes how they change.
+pub fn analyze<I: IntoIterator<Item = i64>>(
+    depths: I,
+    size: usize,
+    aggregation: Aggregation,
+) -> Report {
+    let mut report = Report::default();
+    let mut window_count = 0;
+    let windows = windows(depths, size, aggregation).inspect(|_| window_count += 1);
+    for run in runs(changes(windows)) {
+        match run.trend {
+            Trend::Increasing => report.increasing.add(run),
+            Trend::Decreasing => report.decreasing.add(run),
+            Trend::Flat => report.flat.add(run),
+        }
+    }
+    report.windows = window_count;
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [i64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_windows() {
+        let values = |size, aggregation| {
+            windows(DEPTHS, size, aggregation)
+                .map(|window| window.value)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(values(3, Aggregation::Sum)[..3], [607.0, 618.0, 618.0]);
+        assert_eq!(values(2, Aggregation::Mean)[..2], [199.5, 204.0]);
+        assert_eq!(
+            values(3, Aggregation::Min),
+            [199.0, 200.0, 200.0, 200.0, 200.0, 207.0, 240.0, 260.0]
+        );
+        assert_eq!(
+            values(3, Aggregation::Max),
+            [208.0, 210.0, 210.0, 210.0, 240.0, 269.0, 269.0, 269.0]
+        );
+        assert_eq!(values(10, Aggregation::Sum).len(), 1);
+        assert!(values(11, Aggregation::Sum).is_empty());
+    }
+
+    #[test]
+    fn test_changes_and_runs() {
+        let changes = changes(windows(DEPTHS, 3, Aggregation::Sum)).collect::<Vec<_>>();
+        assert_eq!(
+            changes[..3],
+            [
+                Change {
+                    position: 1,
+                    trend: Trend::Increasing
+                },
+                Change {
+                    position: 2,
+                    trend: Trend::Flat
+                },
+                Change {
+                    position: 3,
+                    trend: Trend::Decreasing
+                },
+            ]
+        );
+
+        let runs = runs(changes).collect::<Vec<_>>();
+        assert_eq!(runs.len(), 4);
+        assert_eq!(
+            runs[3],
+            Run {
+                trend: Trend::Increasing,
+                start: 4,
+                length: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let report = analyze(DEPTHS, 1, Aggregation::Sum);
+        assert_eq!(report.windows, 10);
+        assert_eq!(report.increasing.changes, 7);
+        assert_eq!(report.decreasing.changes, 2);
+        assert_eq!(report.flat, RunStats::default());
+        assert_eq!(
+            report.longest_climb(),
+            Some(Run {
+                trend: Trend::Increasing,
+                start: 1,
+                length: 3
+            })
+        );
+        assert_eq!(report.stats(Trend::Increasing).runs, 3);
+    }
+
+    #[test]
+    fn test_analyze_long_stream() {
+        // A saw tooth of a million depths that is never collected
+        let depths = (0..1_000_000i64).map(|i| i % 1000);
+        let report = analyze(depths, 100, Aggregation::Max);
+        assert_eq!(report.windows, 999_901);
+        assert_eq!(report.increasing.changes, 900_000);
+        assert_eq!(report.decreasing.changes, 999);
+        assert_eq!(
+            report.longest_climb(),
+            Some(Run {
+                trend: Trend::Increasing,
+                start: 1,
+                length: 900
+            })
+        );
+    }
+}
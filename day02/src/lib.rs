@@ -0,0 +1,77 @@
+pub mod submarine;
+
+use aoc_utils::{PuzzleInput, Solution};
+use submarine::{AimModel, Command, MovementModel, SimpleModel, Submarine};
+
+const DAY: u8 = 2;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Command>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn parse(input: &PuzzleInput) -> Vec<Command> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<Command>) -> i32 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<Command>) -> i32 {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<Command> {
+    input.parse_lines().expect("Invalid puzzle input")
+}
+
+/// Multiplies the final horizontal position with the final depth.
+fn final_product(model: impl MovementModel, commands: &[Command]) -> i32 {
+    let position = Submarine::new(model).run(commands);
+    position.horizontal * position.depth
+}
+
+pub fn solve_a(commands: &[Command]) -> i32 {
+    final_product(SimpleModel, commands)
+}
+
+pub fn solve_b(commands: &[Command]) -> i32 {
+    final_product(AimModel, commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const EXAMPLE_INPUT: &str = "forward 5\n\
+    down 5\n\
+    forward 8\n\
+    up 3\n\
+    down 8\n\
+    forward 2";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        let input = parse(&PuzzleInput::new(EXAMPLE_INPUT));
+        assert_eq!(150, solve_a(&input))
+    }
+
+    #[test]
+    fn test_solve_b() {
+        let input = parse(&PuzzleInput::new(EXAMPLE_INPUT));
+        assert_eq!(900, solve_b(&input))
+    }
+}
@@ -0,0 +1,283 @@
+use aoc_utils::pattern::captures;
+use aoc_utils::{Error, FromPuzzleLine};
+
+/// A single line of the planned course.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Back(i32),
+    Down(i32),
+    Up(i32),
+    /// Rises straight up to the surface.
+    Surface,
+}
+
+impl FromPuzzleLine for Command {
+    fn from_line(line: &str) -> Result<Command, Error> {
+        if line == "surface" {
+            return Ok(Command::Surface);
+        }
+
+        let captures = match captures("{} {}", line) {
+            Ok(captures) => captures,
+            Err(_) if !line.contains(' ') => {
+                return Err(Error::Parse {
+                    line: 1,
+                    column: line.chars().count() + 1,
+                    token: String::new(),
+                    message: format!("missing distance after {:?}", line),
+                })
+            }
+            Err(e) => return Err(e),
+        };
+        let (name, distance) = (captures[0], captures[1]);
+        let command: fn(i32) -> Command = match name.text {
+            "forward" => Command::Forward,
+            "back" => Command::Back,
+            "down" => Command::Down,
+            "up" => Command::Up,
+            "surface" => {
+                return Err(Error::Parse {
+                    line: 1,
+                    column: distance.column,
+                    token: distance.text.to_string(),
+                    message: "surface doesn't take a distance".to_string(),
+                })
+            }
+            _ => {
+                return Err(Error::Parse {
+                    line: 1,
+                    column: name.column,
+                    token: name.text.to_string(),
+                    message: "unknown command, expected forward, back, down, up or surface"
+                        .to_string(),
+                })
+            }
+        };
+        Ok(command(distance.parse()?))
+    }
+}
+
+/// Where the submarine is and where it is heading.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i32,
+    pub depth: i32,
+    /// Only used by models that steer, like the [`AimModel`].
+    pub aim: i32,
+}
+
+/// The rules how a command moves the submarine, the puzzle describes [`SimpleModel`] and [`AimModel`].
+pub trait MovementModel {
+    fn apply(&mut self, position: Position, command: Command) -> Position;
+}
+
+/// Part A: forward and back move horizontally, down and up change the depth directly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimpleModel;
+
+impl MovementModel for SimpleModel {
+    fn apply(&mut self, position: Position, command: Command) -> Position {
+        match command {
+            Command::Forward(x) => Position {
+                horizontal: position.horizontal + x,
+                ..position
+            },
+            Command::Back(x) => Position {
+                horizontal: position.horizontal - x,
+                ..position
+            },
+            Command::Down(x) => Position {
+                depth: position.depth + x,
+                ..position
+            },
+            Command::Up(x) => Position {
+                depth: position.depth - x,
+                ..position
+            },
+            Command::Surface => Position {
+                depth: 0,
+                ..position
+            },
+        }
+    }
+}
+
+/// Part B: down and up change the aim, forward moves along it and back retraces it.
+/// Surfacing also levels the submarine out.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AimModel;
+
+impl MovementModel for AimModel {
+    fn apply(&mut self, position: Position, command: Command) -> Position {
+        match command {
+            Command::Forward(x) => Position {
+                horizontal: position.horizontal + x,
+                depth: position.depth + position.aim * x,
+                ..position
+            },
+            Command::Back(x) => Position {
+                horizontal: position.horizontal - x,
+                depth: position.depth - position.aim * x,
+                ..position
+            },
+            Command::Down(x) => Position {
+                aim: position.aim + x,
+                ..position
+            },
+            Command::Up(x) => Position {
+                aim: position.aim - x,
+                ..position
+            },
+            Command::Surface => Position {
+                depth: 0,
+                aim: 0,
+                ..position
+            },
+        }
+    }
+}
+
+/// A submarine starting at the surface, moved according to its model.
+pub struct Submarine<M> {
+    model: M,
+    position: Position,
+}
+
+impl<M: MovementModel> Submarine<M> {
+    pub fn new(model: M) -> Submarine<M> {
+        Submarine {
+            model,
+            position: Position::default(),
+        }
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Executes a single command and returns the new position.
+    pub fn execute(&mut self, command: Command) -> Position {
+        self.position = self.model.apply(self.position, command);
+        self.position
+    }
+
+    /// Executes all commands and returns the final position.
+    pub fn run(&mut self, commands: &[Command]) -> Position {
+        for &command in commands {
+            self.execute(command);
+        }
+        self.position
+    }
+
+    /// Executes the commands lazily, yielding the position after each of them.
+    pub fn trajectory<'a, I>(&'a mut self, commands: I) -> impl Iterator<Item = Position> + 'a
+    where
+        I: IntoIterator<Item = Command>,
+        I::IntoIter: 'a,
+    {
+        commands
+            .into_iter()
+            .map(move |command| self.execute(command))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::PuzzleInput;
+
+    fn parse_error(line: &str) -> String {
+        Command::from_line(line).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_parse() {
+        let commands = PuzzleInput::new("forward 5\nback 2\n\ndown -3\nup 1\nsurface")
+            .parse_lines::<Command>()
+            .unwrap();
+        assert_eq!(
+            commands,
+            vec![
+                Command::Forward(5),
+                Command::Back(2),
+                Command::Down(-3),
+                Command::Up(1),
+                Command::Surface
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_error("sideways 3"),
+            "Invalid token \"sideways\" at line 1, column 1: \
+             unknown command, expected forward, back, down, up or surface"
+        );
+        assert_eq!(
+            parse_error("forward"),
+            "Invalid token \"\" at line 1, column 8: missing distance after \"forward\""
+        );
+        assert_eq!(
+            parse_error("down x"),
+            "Invalid token \"x\" at line 1, column 6: invalid digit found in string"
+        );
+        assert_eq!(
+            parse_error("surface 4"),
+            "Invalid token \"4\" at line 1, column 9: surface doesn't take a distance"
+        );
+
+        let error = PuzzleInput::new("up 1\nforward 2 3")
+            .parse_lines::<Command>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"2 3\" at line 2, column 9: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let commands = [Command::Down(2), Command::Forward(3), Command::Back(1)];
+        let mut submarine = Submarine::new(AimModel);
+        let trajectory = submarine.trajectory(commands).collect::<Vec<_>>();
+        assert_eq!(
+            trajectory,
+            vec![
+                Position {
+                    horizontal: 0,
+                    depth: 0,
+                    aim: 2
+                },
+                Position {
+                    horizontal: 3,
+                    depth: 6,
+                    aim: 2
+                },
+                Position {
+                    horizontal: 2,
+                    depth: 4,
+                    aim: 2
+                },
+            ]
+        );
+        assert_eq!(submarine.position(), trajectory[2]);
+    }
+
+    #[test]
+    fn test_surface() {
+        let commands = [Command::Down(4), Command::Forward(2), Command::Surface];
+        let simple = Submarine::new(SimpleModel).run(&commands);
+        assert_eq!((simple.horizontal, simple.depth), (2, 0));
+        let aim = Submarine::new(AimModel).run(&commands);
+        assert_eq!(
+            aim,
+            Position {
+                horizontal: 2,
+                depth: 0,
+                aim: 0
+            }
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use aoc_utils::{Error, FromPuzzleLine, PuzzleInput};
+use std::fmt;
+use std::fmt::Display;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+fn word_count(width: usize) -> usize {
+    width.div_ceil(WORD_BITS)
+}
+
+/// Mask of a column within its word.
+fn column_mask(column: usize) -> u64 {
+    1 << (WORD_BITS - 1 - column % WORD_BITS)
+}
+
+/// A line of the report, or a value computed from its columns like the gamma rate.
+/// Packed into 64 bit words, the first column being the most significant bit of the first word.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bits {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn zeros(width: usize) -> Bits {
+        Bits {
+            width,
+            words: vec![0; word_count(width)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The bit of a column, the first column is 0.
+    pub fn get(&self, column: usize) -> bool {
+        assert!(column < self.width, "Column {} is out of range", column);
+        self.words[column / WORD_BITS] & column_mask(column) != 0
+    }
+
+    fn set(&mut self, column: usize, bit: bool) {
+        if bit {
+            self.words[column / WORD_BITS] |= column_mask(column);
+        } else {
+            self.words[column / WORD_BITS] &= !column_mask(column);
+        }
+    }
+
+    /// The bits as a number, `None` if they are wider than 64 bits.
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.words[..] {
+            [word] => Some(word >> (WORD_BITS - self.width)),
+            _ => None,
+        }
+    }
+}
+
+impl FromPuzzleLine for Bits {
+    fn from_line(line: &str) -> Result<Bits, Error> {
+        let width = line.chars().count();
+        if width == 0 {
+            return Err(Error::Parse {
+                line: 1,
+                column: 1,
+                token: String::new(),
+                message: "expected at least one bit".to_string(),
+            });
+        }
+
+        let mut bits = Bits::zeros(width);
+        for (column, c) in line.chars().enumerate() {
+            match c {
+                '0' => {}
+                '1' => bits.set(column, true),
+                _ => {
+                    return Err(Error::Parse {
+                        line: 1,
+                        column: column + 1,
+                        token: c.to_string(),
+                        message: "expected 0 or 1".to_string(),
+                    })
+                }
+            }
+        }
+        Ok(bits)
+    }
+}
+
+impl Display for Bits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for column in 0..self.width {
+            write!(f, "{}", if self.get(column) { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the more or the less common bit of a column is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Criterion {
+    MostCommon,
+    LeastCommon,
+}
+
+/// Decides which bit is selected in a column where both bits are equally common.
+pub trait TieBreak {
+    fn break_tie(&self, column: usize) -> bool;
+}
+
+/// Always selects the same bit on ties, like the puzzle does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tie {
+    One,
+    Zero,
+}
+
+impl TieBreak for Tie {
+    fn break_tie(&self, _column: usize) -> bool {
+        *self == Tie::One
+    }
+}
+
+/// Any function from the column to the selected bit can break ties.
+impl<F: Fn(usize) -> bool> TieBreak for F {
+    fn break_tie(&self, column: usize) -> bool {
+        self(column)
+    }
+}
+
+impl Criterion {
+    /// Selects a bit given how many lines have a 1 and a 0 in the column.
+    pub fn select(&self, column: usize, ones: usize, zeros: usize, ties: &impl TieBreak) -> bool {
+        match ones.cmp(&zeros) {
+            std::cmp::Ordering::Equal => ties.break_tie(column),
+            order => (order == std::cmp::Ordering::Greater) == (*self == Criterion::MostCommon),
+        }
+    }
+}
+
+/// Number of lines with a 1 and with a 0 in a column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnCount {
+    pub ones: usize,
+    pub zeros: usize,
+}
+
+/// One elimination round of [`Report::filter`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub column: usize,
+    /// Counts of the lines that were left before this round.
+    pub count: ColumnCount,
+    /// The bit the remaining lines have in this column.
+    pub kept: bool,
+    /// Whether the bit was chosen by the tie break.
+    pub tie: bool,
+    /// Number of lines left after this round.
+    pub remaining: usize,
+}
+
+/// Result of filtering a report down to a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filtering {
+    pub rating: Bits,
+    /// Index of the rating in the report.
+    pub line: usize,
+    pub rounds: Vec<Round>,
+}
+
+/// A diagnostic report whose lines all have the same width.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    width: usize,
+    len: usize,
+    /// The words of all lines after each other.
+    words: Vec<u64>,
+}
+
+impl Report {
+    /// Parses the lines of binary numbers, blank lines are skipped.
+    pub fn parse(input: &PuzzleInput) -> Result<Report, Error> {
+        let mut report: Option<Report> = None;
+        for (i, line) in input.lines_iter().enumerate() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+            let line_number = input.first_line() + i;
+            let bits = Bits::from_line(line).map_err(|e| match e {
+                Error::Parse {
+                    column,
+                    token,
+                    message,
+                    ..
+                } => Error::Parse {
+                    line: line_number,
+                    column,
+                    token,
+                    message,
+                },
+                e => e,
+            })?;
+
+            let report = report.get_or_insert_with(|| Report {
+                width: bits.width,
+                len: 0,
+                words: vec![],
+            });
+            if bits.width != report.width {
+                return Err(Error::Parse {
+                    line: line_number,
+                    column: 1,
+                    token: line.to_string(),
+                    message: format!("expected {} bits like the lines before", report.width),
+                });
+            }
+            report.words.extend(bits.words);
+            report.len += 1;
+        }
+
+        report.ok_or_else(|| Error::Parse {
+            line: input.first_line(),
+            column: 1,
+            token: String::new(),
+            message: "the report is empty".to_string(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn words(&self, line: usize) -> &[u64] {
+        let count = word_count(self.width);
+        &self.words[line * count..(line + 1) * count]
+    }
+
+    fn bit(&self, line: usize, column: usize) -> bool {
+        self.words(line)[column / WORD_BITS] & column_mask(column) != 0
+    }
+
+    pub fn line(&self, line: usize) -> Bits {
+        Bits {
+            width: self.width,
+            words: self.words(line).to_vec(),
+        }
+    }
+
+    /// Counts the bits of every column in a single pass over the packed lines.
+    pub fn column_counts(&self) -> Vec<ColumnCount> {
+        let mut ones = vec![0; self.width];
+        for (i, &word) in self.words.iter().enumerate() {
+            let first_column = i % word_count(self.width) * WORD_BITS;
+            let mut word = word;
+            while word != 0 {
+                ones[first_column + WORD_BITS - 1 - word.trailing_zeros() as usize] += 1;
+                word &= word - 1;
+            }
+        }
+
+        ones.into_iter()
+            .map(|ones| ColumnCount {
+                ones,
+                zeros: self.len - ones,
+            })
+            .collect()
+    }
+
+    /// The bit selected by the criterion in every column, e.g. the gamma rate for the most
+    /// common bits.
+    pub fn common_bits(&self, criterion: Criterion, ties: &impl TieBreak) -> Bits {
+        let mut bits = Bits::zeros(self.width);
+        for (column, count) in self.column_counts().into_iter().enumerate() {
+            bits.set(
+                column,
+                criterion.select(column, count.ones, count.zeros, ties),
+            );
+        }
+        bits
+    }
+
+    /// Keeps the lines with the selected bit in the first column, then in the second column and
+    /// so on, until a single line is left. Lines that are equal in all columns can't be told
+    /// apart, the first of them is the rating then. Returns `None` for an empty report.
+    pub fn filter(&self, criterion: Criterion, ties: &impl TieBreak) -> Option<Filtering> {
+        let mut lines = (0..self.len).collect::<Vec<_>>();
+        let mut rounds = vec![];
+
+        for column in 0..self.width {
+            if lines.len() <= 1 {
+                break;
+            }
+            let ones = lines.iter().filter(|&&line| self.bit(line, column)).count();
+            let count = ColumnCount {
+                ones,
+                zeros: lines.len() - ones,
+            };
+            let kept = criterion.select(column, count.ones, count.zeros, ties);
+            lines.retain(|&line| self.bit(line, column) == kept);
+            rounds.push(Round {
+                column,
+                count,
+                kept,
+                tie: count.ones == count.zeros,
+                remaining: lines.len(),
+            });
+        }
+
+        let &line = lines.first()?;
+        Some(Filtering {
+            rating: self.line(line),
+            line,
+            rounds,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str =
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+    #[test]
+    fn test_parse() {
+        let report = Report::parse(&PuzzleInput::new(EXAMPLE_INPUT)).unwrap();
+        assert_eq!((report.len(), report.width()), (12, 5));
+        assert_eq!(report.line(1).to_u64(), Some(0b11110));
+        assert_eq!(report.line(1).to_string(), "11110");
+
+        let error = Report::parse(&PuzzleInput::new("0101\n\n0121")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"2\" at line 3, column 3: expected 0 or 1"
+        );
+        let error = Report::parse(&PuzzleInput::new("0101\n010")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"010\" at line 2, column 1: expected 4 bits like the lines before"
+        );
+        assert!(Report::parse(&PuzzleInput::new("\n")).is_err());
+    }
+
+    #[test]
+    fn test_wide_report() {
+        // 130 columns span three words, only the first and the last column differ
+        let line = |first: char, last: char| format!("{}{}{}", first, "10".repeat(64), last);
+        let input = [line('1', '0'), line('0', '1'), line('1', '1')].join("\n");
+        let report = Report::parse(&PuzzleInput::new(input)).unwrap();
+        assert_eq!(report.width(), 130);
+
+        let counts = report.column_counts();
+        assert_eq!(counts[0], ColumnCount { ones: 2, zeros: 1 });
+        assert_eq!(counts[1], ColumnCount { ones: 3, zeros: 0 });
+        assert_eq!(counts[2], ColumnCount { ones: 0, zeros: 3 });
+        assert_eq!(counts[129], ColumnCount { ones: 2, zeros: 1 });
+
+        let gamma = report.common_bits(Criterion::MostCommon, &Tie::Zero);
+        assert_eq!(gamma.to_string(), line('1', '1'));
+        assert_eq!(gamma.to_u64(), None);
+
+        let filtering = report.filter(Criterion::LeastCommon, &Tie::Zero).unwrap();
+        assert_eq!(filtering.line, 1);
+        assert_eq!(filtering.rounds.len(), 1);
+    }
+
+    #[test]
+    fn test_filter_trace() {
+        let report = Report::parse(&PuzzleInput::new(EXAMPLE_INPUT)).unwrap();
+        let oxygen = report.filter(Criterion::MostCommon, &Tie::One).unwrap();
+        assert_eq!(oxygen.rating.to_u64(), Some(23));
+        assert_eq!(
+            oxygen
+                .rounds
+                .iter()
+                .map(|round| round.remaining)
+                .collect::<Vec<_>>(),
+            vec![7, 4, 3, 2, 1]
+        );
+        assert_eq!(
+            oxygen.rounds[4],
+            Round {
+                column: 4,
+                count: ColumnCount { ones: 1, zeros: 1 },
+                kept: true,
+                tie: true,
+                remaining: 1
+            }
+        );
+
+        // Keeping zeros on ties instead finds 10110 in the last round
+        let oxygen = report.filter(Criterion::MostCommon, &Tie::Zero).unwrap();
+        assert_eq!(oxygen.rating.to_string(), "10110");
+
+        // Custom tie breaks get the column, keeping a 1 in column 2 finds 01111 instead of 01010
+        // Plain remainder like in the benchmarks, is_multiple_of needs a newer toolchain
+        #[allow(clippy::manual_is_multiple_of)]
+        let alternating = |column: usize| column % 2 == 0;
+        let co2 = report.filter(Criterion::LeastCommon, &alternating).unwrap();
+        assert_eq!(co2.rating.to_u64(), Some(15));
+    }
+}
@@ -0,0 +1,80 @@
+pub mod diagnostic;
+
+use aoc_utils::{PuzzleInput, Solution};
+use diagnostic::{Bits, Criterion, Report, Tie};
+const DAY: u8 = 3;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = DAY;
+    type Input = Report;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &PuzzleInput) -> Report {
+        parse(input)
+    }
+
+    fn solve_a(input: &Report) -> u64 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Report) -> u64 {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Report {
+    Report::parse(input).expect("Invalid puzzle input")
+}
+
+pub fn solve_a(report: &Report) -> u64 {
+    // Ties count as 0 for gamma and as 1 for epsilon
+    let gamma = report.common_bits(Criterion::MostCommon, &Tie::Zero);
+    let epsilon = report.common_bits(Criterion::LeastCommon, &Tie::One);
+    to_u64(&gamma) * to_u64(&epsilon)
+}
+
+pub fn solve_b(report: &Report) -> u64 {
+    let rating = |criterion: Criterion, ties: Tie| {
+        let filtering = report
+            .filter(criterion, &ties)
+            .expect("The report is empty");
+        to_u64(&filtering.rating)
+    };
+    let oxygen_generator_rating = rating(Criterion::MostCommon, Tie::One);
+    let co2_scrubber_rating = rating(Criterion::LeastCommon, Tie::Zero);
+    oxygen_generator_rating * co2_scrubber_rating
+}
+
+fn to_u64(bits: &Bits) -> u64 {
+    bits.to_u64()
+        .expect("The answer needs a report of at most 64 bits")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const EXAMPLE_INPUT: &str =
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(EXAMPLE_INPUT))), 198);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(EXAMPLE_INPUT))), 230);
+    }
+}
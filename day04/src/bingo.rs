@@ -0,0 +1,277 @@
+use aoc_utils::{Error, PuzzleInput};
+use std::collections::HashMap;
+
+/// A board of `height` rows with `width` numbers each.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BingoBoard {
+    width: usize,
+    height: usize,
+    /// The numbers row by row.
+    numbers: Vec<u32>,
+}
+
+impl BingoBoard {
+    /// Parses a board with one row per line and the numbers separated by whitespace.
+    pub fn parse(input: &PuzzleInput) -> Result<BingoBoard, Error> {
+        let mut width = None;
+        let mut height = 0;
+        let mut numbers = vec![];
+
+        for (i, line) in input.lines_iter().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let line_number = input.first_line() + i;
+            let column = |token: &str| {
+                let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+                line[..offset].chars().count() + 1
+            };
+
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            if *width.get_or_insert(tokens.len()) != tokens.len() {
+                return Err(Error::Parse {
+                    line: line_number,
+                    column: column(tokens[0]),
+                    token: line.trim().to_string(),
+                    message: format!(
+                        "expected {} numbers like the rows before",
+                        width.unwrap_or_default()
+                    ),
+                });
+            }
+            for token in tokens {
+                numbers.push(token.parse().map_err(|e| Error::Parse {
+                    line: line_number,
+                    column: column(token),
+                    token: token.to_string(),
+                    message: format!("{}", e),
+                })?);
+            }
+            height += 1;
+        }
+
+        Ok(BingoBoard {
+            width: width.unwrap_or_default(),
+            height,
+            numbers,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> u32 {
+        self.numbers[row * self.width + column]
+    }
+}
+
+/// Which lines of a board win once all of their numbers are marked.
+/// Rows and columns always win.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rules {
+    /// Whether the two diagonals win too, only on square boards.
+    pub diagonals: bool,
+}
+
+/// A board that got bingo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    /// Index of the board in the game.
+    pub board: usize,
+    /// Index of the drawn number that completed a line.
+    pub turn: usize,
+    pub number: u32,
+    /// Sum of the unmarked numbers of the board times the winning number.
+    pub score: u32,
+}
+
+/// The numbers that are drawn and the boards playing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BingoGame {
+    pub numbers: Vec<u32>,
+    pub boards: Vec<BingoBoard>,
+}
+
+/// Marked numbers of a board during a game. They are counted per row, column and diagonal,
+/// so that marking a number only touches the lines of its cell.
+struct BoardState {
+    marked: Vec<bool>,
+    row_hits: Vec<usize>,
+    column_hits: Vec<usize>,
+    /// Main diagonal and anti-diagonal.
+    diagonal_hits: [usize; 2],
+    unmarked_sum: u32,
+    won: bool,
+}
+
+impl BoardState {
+    fn new(board: &BingoBoard) -> BoardState {
+        BoardState {
+            marked: vec![false; board.numbers.len()],
+            row_hits: vec![0; board.height],
+            column_hits: vec![0; board.width],
+            diagonal_hits: [0; 2],
+            unmarked_sum: board.numbers.iter().sum(),
+            won: false,
+        }
+    }
+
+    /// Marks a cell and returns whether it completed a line.
+    fn mark(&mut self, board: &BingoBoard, cell: usize, rules: Rules) -> bool {
+        if self.marked[cell] {
+            return false;
+        }
+        self.marked[cell] = true;
+        self.unmarked_sum -= board.numbers[cell];
+
+        let (row, column) = (cell / board.width, cell % board.width);
+        self.row_hits[row] += 1;
+        self.column_hits[column] += 1;
+        let mut complete =
+            self.row_hits[row] == board.width || self.column_hits[column] == board.height;
+
+        if rules.diagonals && board.width == board.height {
+            let size = board.width;
+            for (diagonal, on_diagonal) in [row == column, row + column == size - 1]
+                .into_iter()
+                .enumerate()
+            {
+                if on_diagonal {
+                    self.diagonal_hits[diagonal] += 1;
+                    complete |= self.diagonal_hits[diagonal] == size;
+                }
+            }
+        }
+        complete
+    }
+}
+
+impl BingoGame {
+    /// Draws the numbers until every board has won or no numbers are left.
+    /// Returns the wins ordered by turn, boards winning in the same turn ordered by their index.
+    /// Boards that never win aren't included.
+    pub fn play(&self, rules: Rules) -> Vec<Win> {
+        let mut cells = HashMap::<u32, Vec<(usize, usize)>>::new();
+        for (board_index, board) in self.boards.iter().enumerate() {
+            for (cell, &number) in board.numbers.iter().enumerate() {
+                cells.entry(number).or_default().push((board_index, cell));
+            }
+        }
+
+        let mut states = self.boards.iter().map(BoardState::new).collect::<Vec<_>>();
+        let mut wins = vec![];
+        for (turn, &number) in self.numbers.iter().enumerate() {
+            if wins.len() == self.boards.len() {
+                break;
+            }
+
+            let mut winners = vec![];
+            for &(board, cell) in cells.get(&number).into_iter().flatten() {
+                let state = &mut states[board];
+                if !state.won && state.mark(&self.boards[board], cell, rules) {
+                    winners.push(board);
+                }
+            }
+
+            // Only won after the draw, the number can be on a winning board more than once
+            // and all of its cells have to be marked before the board is scored
+            winners.sort_unstable();
+            winners.dedup();
+            for &board in &winners {
+                states[board].won = true;
+            }
+            wins.extend(winners.into_iter().map(|board| Win {
+                board,
+                turn,
+                number,
+                score: states[board].unmarked_sum * number,
+            }));
+        }
+        wins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_board() {
+        let board = BingoBoard::parse(&PuzzleInput::new(" 1  2  3\n4 5 6\n")).unwrap();
+        assert_eq!((board.width(), board.height()), (3, 2));
+        assert_eq!(board.get(1, 2), 6);
+
+        let error = BingoBoard::parse(&PuzzleInput::new("1 2\n3  x")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"x\" at line 2, column 4: invalid digit found in string"
+        );
+        let error = BingoBoard::parse(&PuzzleInput::new("1 2\n 3")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid token \"3\" at line 2, column 2: expected 2 numbers like the rows before"
+        );
+    }
+
+    #[test]
+    fn test_rectangular_boards() {
+        let game = BingoGame {
+            numbers: vec![5, 1, 2, 4, 3],
+            boards: ["1 2 3\n4 5 6", "3 5\n4 1\n2 6"]
+                .map(|rows| BingoBoard::parse(&PuzzleInput::new(rows)).unwrap())
+                .to_vec(),
+        };
+        let wins = game.play(Rules::default());
+        assert_eq!(
+            wins,
+            vec![
+                Win {
+                    board: 0,
+                    turn: 2,
+                    number: 2,
+                    score: (3 + 4 + 6) * 2
+                },
+                Win {
+                    board: 1,
+                    turn: 3,
+                    number: 4,
+                    score: (3 + 6) * 4
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagonals() {
+        let game = BingoGame {
+            numbers: vec![1, 5, 9, 3, 7],
+            boards: ["1 2 3\n4 5 6\n7 8 9", "1 2\n4 5\n7 8"]
+                .map(|rows| BingoBoard::parse(&PuzzleInput::new(rows)).unwrap())
+                .to_vec(),
+        };
+        assert_eq!(game.play(Rules::default()), vec![]);
+
+        // The rectangular board has no diagonals
+        let wins = game.play(Rules { diagonals: true });
+        assert_eq!(wins.len(), 1);
+        assert_eq!((wins[0].board, wins[0].turn), (0, 2));
+        assert_eq!(wins[0].score, (2 + 3 + 4 + 6 + 7 + 8) * 9);
+    }
+
+    #[test]
+    fn test_number_on_board_twice() {
+        // Drawing 1 completes the first row, the 1 in the second row is marked before scoring
+        let game = BingoGame {
+            numbers: vec![2, 1],
+            boards: vec![BingoBoard::parse(&PuzzleInput::new("1 2\n1 3")).unwrap()],
+        };
+        let wins = game.play(Rules::default());
+        assert_eq!(wins.len(), 1);
+        assert_eq!((wins[0].turn, wins[0].score), (1, 3));
+    }
+}
@@ -0,0 +1,93 @@
+pub mod bingo;
+
+use aoc_utils::{PuzzleInput, Solution};
+use bingo::{BingoBoard, BingoGame, Rules, Win};
+
+const DAY: u8 = 4;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = DAY;
+    type Input = (BingoGame, Vec<Win>);
+    type AnswerA = u32;
+    type AnswerB = u32;
+
+    fn parse(input: &PuzzleInput) -> (BingoGame, Vec<Win>) {
+        parse(input)
+    }
+
+    fn solve_a(input: &(BingoGame, Vec<Win>)) -> u32 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &(BingoGame, Vec<Win>)) -> u32 {
+        solve_b(input)
+    }
+}
+
+/// Parses the game and plays it, both parts only look at the order in which the boards win.
+pub fn parse(input: &PuzzleInput) -> (BingoGame, Vec<Win>) {
+    let (numbers, boards) = input
+        .parse_header_body(
+            |numbers| numbers.try_convert_to_ints::<u32>(),
+            |boards| boards.sections().iter().map(BingoBoard::parse).collect(),
+        )
+        .expect("Invalid puzzle input");
+    let game = BingoGame { numbers, boards };
+    let wins = game.play(Rules::default());
+    (game, wins)
+}
+
+pub fn solve_a((_, wins): &(BingoGame, Vec<Win>)) -> u32 {
+    wins.first().expect("No board ever wins?").score
+}
+
+pub fn solve_b((game, wins): &(BingoGame, Vec<Win>)) -> u32 {
+    assert_eq!(wins.len(), game.boards.len(), "Some boards never win");
+    wins.last().unwrap().score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str =
+        "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1\n\
+                               \n\
+                               22 13 17 11  0\n\
+                               8  2 23  4 24\n\
+                               21  9 14 16  7\n\
+                               6 10  3 18  5\n\
+                               1 12 20 15 19\n\
+                               \n\
+                               3 15  0  2 22\n\
+                               9 18 13 17  5\n\
+                               19  8  7 25 23\n\
+                               20 11 10 24  4\n\
+                               14 21 16 12  6\n\
+                               \n\
+                               14 21 17 24  4\n\
+                               10 16 15  9 19\n\
+                               18  8 23 26 20\n\
+                               22 11 13  6  5\n\
+                               2  0 12  3  7";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 4512);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 1924);
+    }
+}
@@ -0,0 +1,76 @@
+pub mod vents;
+
+use aoc_utils::{PuzzleInput, Solution};
+use vents::{Line, VentMap};
+const DAY: u8 = 5;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Line>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> Vec<Line> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<Line>) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<Line>) -> usize {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<Line> {
+    input.parse_lines().expect("Invalid puzzle input")
+}
+
+pub fn solve_a(lines: &[Line]) -> usize {
+    lines
+        .iter()
+        .filter(|line| line.is_axis_aligned())
+        .collect::<VentMap>()
+        .overlap_count()
+}
+
+pub fn solve_b(lines: &[Line]) -> usize {
+    lines.iter().collect::<VentMap>().overlap_count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "0,9 -> 5,9\n\
+                              8,0 -> 0,8\n\
+                              9,4 -> 3,4\n\
+                              2,2 -> 2,1\n\
+                              7,0 -> 7,4\n\
+                              6,4 -> 2,0\n\
+                              0,9 -> 2,9\n\
+                              3,4 -> 1,4\n\
+                              0,0 -> 8,8\n\
+                              5,5 -> 8,2";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 5);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 12);
+    }
+}
@@ -0,0 +1,517 @@
+use aoc_utils::pattern::captures;
+use aoc_utils::{Error, FromPuzzleLine};
+
+pub type Point = (i64, i64);
+
+/// Largest absolute value of a coordinate, so that differences of coordinates fit into an `i64`
+/// and their cross products into an `i128`.
+pub const MAX_COORDINATE: i64 = 1 << 61;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl FromPuzzleLine for Line {
+    fn from_line(line: &str) -> Result<Line, Error> {
+        let captures = captures("{},{} -> {},{}", line)?;
+        let mut coordinates = [0i64; 4];
+        for (coordinate, capture) in coordinates.iter_mut().zip(&captures) {
+            *coordinate = capture.parse()?;
+            if coordinate.unsigned_abs() > MAX_COORDINATE as u64 {
+                return Err(Error::Parse {
+                    line: 1,
+                    column: capture.column,
+                    token: capture.text.to_string(),
+                    message: format!("coordinates are limited to ±{}", MAX_COORDINATE),
+                });
+            }
+        }
+        let [x1, y1, x2, y2] = coordinates;
+        Ok(Line {
+            start: (x1, y1),
+            end: (x2, y2),
+        })
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn cross(a: Point, b: Point) -> i128 {
+    a.0 as i128 * b.1 as i128 - a.1 as i128 * b.0 as i128
+}
+
+impl Line {
+    /// Whether the line is horizontal or vertical.
+    pub fn is_axis_aligned(&self) -> bool {
+        self.start.0 == self.end.0 || self.start.1 == self.end.1
+    }
+
+    /// Whether the line is at 45 degrees.
+    pub fn is_diagonal(&self) -> bool {
+        let (step, _) = self.steps();
+        step.0.abs() == 1 && step.1.abs() == 1
+    }
+
+    /// The smallest integer step from the start towards the end that stays on the line,
+    /// and how many of them lead to the end. Panics if a coordinate exceeds [`MAX_COORDINATE`].
+    fn steps(&self) -> (Point, i64) {
+        assert!(
+            [self.start.0, self.start.1, self.end.0, self.end.1]
+                .iter()
+                .all(|coordinate| coordinate.unsigned_abs() <= MAX_COORDINATE as u64),
+            "Coordinates of {:?} are limited to ±{}",
+            self,
+            MAX_COORDINATE
+        );
+        let (dx, dy) = (self.end.0 - self.start.0, self.end.1 - self.start.1);
+        match gcd(dx, dy) {
+            0 => ((0, 0), 0),
+            steps => ((dx / steps, dy / steps), steps),
+        }
+    }
+
+    /// The points with integer coordinates on the line, from its start to its end.
+    /// Consecutive points are the smallest integer step apart that stays on the line,
+    /// for horizontal, vertical and diagonal lines that is every cell they cross.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let ((step_x, step_y), steps) = self.steps();
+        let start = self.start;
+        (0..=steps).map(move |i| (start.0 + i * step_x, start.1 + i * step_y))
+    }
+}
+
+/// A point covered by at least two lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overlap {
+    pub point: Point,
+    /// Number of lines covering the point.
+    pub lines: usize,
+}
+
+/// The infinite line through the lattice points of all lines with the same direction and offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Carrier {
+    /// Smallest step between lattice points, pointing right or, if vertical, down.
+    step: Point,
+    /// Cross product of the step with any point of the carrier.
+    offset: i128,
+}
+
+impl Carrier {
+    /// Single points are treated as horizontal lines.
+    fn of(line: &Line) -> Carrier {
+        let step = match line.steps().0 {
+            (0, 0) => (1, 0),
+            (x, y) if x < 0 || (x == 0 && y < 0) => (-x, -y),
+            step => step,
+        };
+        Carrier {
+            step,
+            offset: cross(step, line.start),
+        }
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        cross(self.step, point) == self.offset
+    }
+
+    /// Position of a point of the carrier along it, consecutive lattice points are `stride` apart.
+    fn position(&self, point: Point) -> i64 {
+        if self.step.0 != 0 {
+            point.0
+        } else {
+            point.1
+        }
+    }
+
+    fn stride(&self) -> i64 {
+        self.position(self.step)
+    }
+}
+
+/// Consecutive lattice points of a carrier that are covered by the same number of lines,
+/// from the position `first` to the position `last`.
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    first: i64,
+    last: i64,
+    lines: usize,
+}
+
+/// The runs of a carrier, ordered by their position.
+#[derive(Debug, Clone)]
+struct CarrierRuns {
+    carrier: Carrier,
+    /// Any lattice point of the carrier.
+    anchor: Point,
+    runs: Vec<Run>,
+}
+
+impl CarrierRuns {
+    /// Merges the lines of a carrier by sweeping over the positions where they start and end.
+    fn new(carrier: Carrier, lines: &[&Line]) -> CarrierRuns {
+        let stride = carrier.stride();
+        let mut events = vec![];
+        for line in lines {
+            let (start, end) = (carrier.position(line.start), carrier.position(line.end));
+            // Lines ending at a position are removed before the ones starting at it are added
+            events.push((start.min(end), true));
+            events.push((start.max(end) + stride, false));
+        }
+        events.sort_unstable();
+
+        let mut runs = vec![];
+        let mut covering = 0;
+        for (i, &(position, starts)) in events.iter().enumerate() {
+            if starts {
+                covering += 1;
+            } else {
+                covering -= 1;
+            }
+            match events.get(i + 1) {
+                Some(&(next, _)) if next > position && covering > 0 => runs.push(Run {
+                    first: position,
+                    last: next - stride,
+                    lines: covering,
+                }),
+                _ => {}
+            }
+        }
+
+        CarrierRuns {
+            carrier,
+            anchor: lines[0].start,
+            runs,
+        }
+    }
+
+    /// Number of lines covering a lattice point of the carrier.
+    fn lines_at(&self, point: Point) -> usize {
+        let position = self.carrier.position(point);
+        let i = self.runs.partition_point(|run| run.last < position);
+        match self.runs.get(i) {
+            Some(run) if run.first <= position => run.lines,
+            _ => 0,
+        }
+    }
+
+    /// The lattice points of the run, ordered by their position.
+    fn points(&self, run: Run) -> impl Iterator<Item = Point> {
+        let (step, stride, anchor) = (self.carrier.step, self.carrier.stride(), self.anchor);
+        let first = (run.first - self.carrier.position(anchor)) / stride;
+        (0..=(run.last - run.first) / stride).map(move |i| {
+            let steps = first + i;
+            (anchor.0 + steps * step.0, anchor.1 + steps * step.1)
+        })
+    }
+
+    /// The lattice point where the carriers cross, if they cross in one.
+    fn crossing(&self, other: &CarrierRuns) -> Option<Point> {
+        let (step, other_step) = (self.carrier.step, other.carrier.step);
+        let denominator = cross(step, other_step);
+        if denominator == 0 {
+            return None;
+        }
+        let between = (
+            other.anchor.0 - self.anchor.0,
+            other.anchor.1 - self.anchor.1,
+        );
+        let numerator = cross(between, other_step);
+        if numerator % denominator != 0 {
+            return None;
+        }
+        // Crossings far outside of the coordinate range don't touch any line
+        let steps = numerator / denominator;
+        let coordinate = |anchor: i64, step: i64| {
+            let coordinate = (step as i128).checked_mul(steps)? + anchor as i128;
+            i64::try_from(coordinate)
+                .ok()
+                .filter(|coordinate| coordinate.unsigned_abs() <= MAX_COORDINATE as u64)
+        };
+        Some((
+            coordinate(self.anchor.0, step.0)?,
+            coordinate(self.anchor.1, step.1)?,
+        ))
+    }
+}
+
+/// Number of lines covering each point. Lines on the same carrier are merged into runs of points
+/// covered by the same number of lines, and carriers of different directions are crossed pairwise,
+/// so neither the length of the lines nor how far apart they are matters for the memory needed.
+#[derive(Debug, Clone, Default)]
+pub struct VentMap {
+    carriers: Vec<CarrierRuns>,
+    /// Points where carriers of different directions cross, ordered by row and then by column.
+    crossings: Vec<Crossing>,
+}
+
+/// A point covered by lines of at least two directions.
+#[derive(Debug, Clone, Copy)]
+struct Crossing {
+    overlap: Overlap,
+    /// Number of carriers whose lines overlap at the point, each of them counts it in its runs.
+    overlapping_runs: usize,
+}
+
+/// Orders points by row and then by column.
+fn row_major((x, y): Point) -> (i64, i64) {
+    (y, x)
+}
+
+impl VentMap {
+    /// Panics if a coordinate exceeds [`MAX_COORDINATE`].
+    pub fn new<'a, I: IntoIterator<Item = &'a Line>>(lines: I) -> VentMap {
+        let mut lines = lines
+            .into_iter()
+            .map(|line| (Carrier::of(line), line))
+            .collect::<Vec<_>>();
+        lines.sort_unstable_by_key(|&(carrier, _)| carrier);
+
+        let mut carriers = vec![];
+        let mut start = 0;
+        while start < lines.len() {
+            let carrier = lines[start].0;
+            let end = start + lines[start..].partition_point(|&(other, _)| other == carrier);
+            let carrier_lines = lines[start..end]
+                .iter()
+                .map(|&(_, line)| line)
+                .collect::<Vec<_>>();
+            carriers.push(CarrierRuns::new(carrier, &carrier_lines));
+            start = end;
+        }
+
+        let mut crossed = vec![];
+        for (i, first) in carriers.iter().enumerate() {
+            for (j, second) in carriers.iter().enumerate().skip(i + 1) {
+                match first.crossing(second) {
+                    Some(point) if first.lines_at(point) > 0 && second.lines_at(point) > 0 => {
+                        crossed.push((point, i));
+                        crossed.push((point, j));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        crossed.sort_unstable_by_key(|&(point, carrier)| (row_major(point), carrier));
+        crossed.dedup();
+
+        let mut crossings: Vec<Crossing> = vec![];
+        for (point, carrier) in crossed {
+            let lines = carriers[carrier].lines_at(point);
+            match crossings.last_mut() {
+                Some(crossing) if crossing.overlap.point == point => {
+                    crossing.overlap.lines += lines;
+                    crossing.overlapping_runs += usize::from(lines > 1);
+                }
+                _ => crossings.push(Crossing {
+                    overlap: Overlap { point, lines },
+                    overlapping_runs: usize::from(lines > 1),
+                }),
+            }
+        }
+
+        VentMap {
+            carriers,
+            crossings,
+        }
+    }
+
+    /// Number of lines covering the point.
+    pub fn coverage(&self, point: Point) -> usize {
+        self.carriers
+            .iter()
+            .filter(|runs| runs.carrier.contains(point))
+            .map(|runs| runs.lines_at(point))
+            .sum()
+    }
+
+    /// The points covered by multiple lines: those where lines of the same direction overlap,
+    /// ordered by direction and position, followed by those where lines of different directions
+    /// cross, ordered by row and then by column.
+    pub fn overlaps(&self) -> impl Iterator<Item = Overlap> + '_ {
+        let is_crossing = move |point: Point| {
+            self.crossings
+                .binary_search_by_key(&row_major(point), |crossing| {
+                    row_major(crossing.overlap.point)
+                })
+                .is_ok()
+        };
+        let runs = self.carriers.iter().flat_map(move |carrier| {
+            carrier
+                .runs
+                .iter()
+                .filter(|run| run.lines > 1)
+                .flat_map(move |&run| {
+                    carrier.points(run).map(move |point| Overlap {
+                        point,
+                        lines: run.lines,
+                    })
+                })
+                .filter(move |overlap| !is_crossing(overlap.point))
+        });
+        runs.chain(self.crossings.iter().map(|crossing| crossing.overlap))
+    }
+
+    /// Counts the overlapping points without visiting them, so it works for lines of any length.
+    pub fn overlap_count(&self) -> usize {
+        let on_runs = self
+            .carriers
+            .iter()
+            .flat_map(|carrier| {
+                let stride = carrier.carrier.stride();
+                carrier
+                    .runs
+                    .iter()
+                    .filter(|run| run.lines > 1)
+                    .map(move |run| ((run.last - run.first) / stride + 1) as usize)
+            })
+            .sum::<usize>();
+        let counted_on_runs = self
+            .crossings
+            .iter()
+            .map(|crossing| crossing.overlapping_runs)
+            .sum::<usize>();
+        on_runs + self.crossings.len() - counted_on_runs
+    }
+}
+
+impl<'a> FromIterator<&'a Line> for VentMap {
+    fn from_iter<I: IntoIterator<Item = &'a Line>>(lines: I) -> VentMap {
+        VentMap::new(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Line::from_line("0,9 -> 5,-9").unwrap(),
+            Line {
+                start: (0, 9),
+                end: (5, -9)
+            }
+        );
+        assert_eq!(
+            Line::from_line("0,0 -> 4611686018427387904,1")
+                .unwrap_err()
+                .to_string(),
+            "Invalid token \"4611686018427387904\" at line 1, column 8: \
+             coordinates are limited to ±2305843009213693952"
+        );
+    }
+
+    #[test]
+    fn test_points() {
+        let points = |start, end| Line { start, end }.points().collect::<Vec<_>>();
+        assert_eq!(points((2, 2), (2, 0)), vec![(2, 2), (2, 1), (2, 0)]);
+        assert_eq!(points((3, 1), (1, 3)), vec![(3, 1), (2, 2), (1, 3)]);
+        assert_eq!(points((0, 0), (6, -4)), vec![(0, 0), (3, -2), (6, -4)]);
+        assert_eq!(points((0, 0), (3, 1)), vec![(0, 0), (3, 1)]);
+        assert_eq!(points((5, 5), (5, 5)), vec![(5, 5)]);
+
+        let line = |start, end| Line { start, end };
+        assert!(line((8, 0), (0, 8)).is_diagonal());
+        assert!(!line((0, 0), (3, 1)).is_diagonal());
+        assert!(!line((5, 5), (5, 5)).is_diagonal());
+        assert!(line((0, 9), (5, 9)).is_axis_aligned());
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let lines = [
+            ((0, 0), (4, 2)),
+            ((2, 1), (2, 5)),
+            ((0, 3), (6, 0)),
+            ((4, 2), (4, 2)),
+            ((6, 3), (0, 0)),
+        ]
+        .map(|(start, end)| Line { start, end });
+        let map = lines.iter().collect::<VentMap>();
+        assert_eq!(
+            map.overlaps().collect::<Vec<_>>(),
+            vec![
+                Overlap {
+                    point: (0, 0),
+                    lines: 2
+                },
+                Overlap {
+                    point: (2, 1),
+                    lines: 3
+                },
+                Overlap {
+                    point: (2, 2),
+                    lines: 2
+                },
+                Overlap {
+                    point: (4, 2),
+                    lines: 3
+                },
+            ]
+        );
+        assert_eq!(map.overlap_count(), 4);
+        assert_eq!(map.coverage((4, 1)), 1);
+        assert_eq!(map.coverage((6, 3)), 1);
+        assert_eq!(map.coverage((9, 9)), 0);
+    }
+
+    #[test]
+    fn test_long_lines() {
+        // Merged into runs, the points of these lines are never stored or visited for counting
+        let long = 1_000_000_000_000;
+        let lines = [
+            ((0, 0), (long, 0)),
+            ((2 * long, 0), (5, 0)),
+            ((7, -3), (7, 3)),
+            ((-2, -2), (2, 2)),
+        ]
+        .map(|(start, end)| Line { start, end });
+        let map = lines.iter().collect::<VentMap>();
+        // All points from 5 to long on the x axis and the crossing of the diagonal at the origin
+        assert_eq!(map.overlap_count(), long as usize - 4 + 1);
+        assert_eq!(map.coverage((7, 0)), 3);
+        assert_eq!(map.coverage((long, 0)), 2);
+        assert_eq!(map.coverage((long + 1, 0)), 1);
+        assert_eq!(
+            map.overlaps()
+                .map(|overlap| (overlap.point, overlap.lines))
+                .take(3)
+                .collect::<Vec<_>>(),
+            vec![((5, 0), 2), ((6, 0), 2), ((8, 0), 2)]
+        );
+    }
+
+    #[test]
+    fn test_far_apart_lines() {
+        // A dense grid spanning these lines would need 10^24 cells
+        let far = 1_000_000_000_000;
+        let lines = [
+            ((-far, -far), (-far + 10, -far)),
+            ((-far + 5, -far - 5), (-far + 5, -far + 5)),
+            ((far, far), (far - 100, far - 50)),
+            ((far - 100, far), (far, far - 50)),
+            (
+                (MAX_COORDINATE, -MAX_COORDINATE),
+                (-MAX_COORDINATE, MAX_COORDINATE),
+            ),
+        ]
+        .map(|(start, end)| Line { start, end });
+        let map = lines.iter().collect::<VentMap>();
+        assert_eq!(
+            map.overlaps()
+                .map(|overlap| overlap.point)
+                .collect::<Vec<_>>(),
+            vec![(-far + 5, -far), (far - 50, far - 25)]
+        );
+    }
+}
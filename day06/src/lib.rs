@@ -0,0 +1,84 @@
+use aoc_utils::{PuzzleInput, Solution};
+const DAY: u8 = 6;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = DAY;
+    type Input = Vec<u8>;
+    type AnswerA = u64;
+    type AnswerB = u64;
+
+    fn parse(input: &PuzzleInput) -> Vec<u8> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<u8>) -> u64 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<u8>) -> u64 {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<u8> {
+    input.try_convert_to_ints().expect("Invalid puzzle input")
+}
+
+fn simulate_fish(initial_fish_states: &[u8], days: u16) -> u64 {
+    // Maps the internal state (the day) of a fish (as index) to the count of how many fish are in that state
+    let mut fish_counts = [0; 9].to_vec();
+
+    for &fish in initial_fish_states {
+        // E.g. this fish is in the first state (0) => increase count at index 0, etc.
+        fish_counts[fish as usize] += 1;
+    }
+
+    for _ in 1..=days {
+        // Because we don't track every fish individually, just how many are in which state, this is now easy
+        let fish_with_state_zero = fish_counts[0];
+
+        // remove these fish from day 0 and re-add them at day 6 (7 because we will reduce it by one with the drain call)
+        fish_counts[7] += fish_with_state_zero;
+        fish_counts.drain(0..1);
+
+        // Add the newly born fish
+        fish_counts.push(fish_with_state_zero);
+    }
+
+    fish_counts.iter().sum()
+}
+
+pub fn solve_a(input: &[u8]) -> u64 {
+    simulate_fish(input, 80)
+}
+
+pub fn solve_b(input: &[u8]) -> u64 {
+    simulate_fish(input, 256)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "3,4,3,1,2";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 5934);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 26984457539);
+    }
+}
@@ -0,0 +1,87 @@
+use aoc_utils::{PuzzleInput, Solution};
+const DAY: u8 = 7;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = DAY;
+    type Input = Vec<i32>;
+    type AnswerA = i32;
+    type AnswerB = i32;
+
+    fn parse(input: &PuzzleInput) -> Vec<i32> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<i32>) -> i32 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<i32>) -> i32 {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<i32> {
+    input
+        .try_convert_to_ints::<i32>()
+        .expect("Invalid puzzle input")
+}
+
+pub fn solve_a(start_positions: &[i32]) -> i32 {
+    let min_position = *start_positions.iter().min().unwrap();
+    let max_position = *start_positions.iter().max().unwrap();
+
+    (min_position..=max_position)
+        .map(|target_position| {
+            start_positions
+                .iter()
+                .map(|pos| target_position - pos)
+                .map(|diff| diff.abs())
+                .sum()
+        })
+        .min()
+        .unwrap()
+}
+
+pub fn solve_b(start_positions: &[i32]) -> i32 {
+    let min_position = *start_positions.iter().min().unwrap();
+    let max_position = *start_positions.iter().max().unwrap();
+
+    (min_position..=max_position)
+        .map(|target_position| {
+            start_positions
+                .iter()
+                .map(|pos| target_position - pos)
+                .map(|diff| diff.abs())
+                .map(|diff| diff * (diff + 1) / 2) // Gaussian sum formula
+                .sum()
+        })
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "16,1,2,0,4,2,7,1,2,14";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 37);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 168);
+    }
+}
@@ -0,0 +1,129 @@
+use aoc_utils::{Grid, PuzzleInput, Solution};
+use std::collections::HashSet;
+const DAY: u8 = 9;
+
+struct Basin {
+    points: HashSet<(usize, usize)>,
+}
+
+impl Basin {
+    fn new(low_point: (usize, usize), grid: &Grid<u8>) -> Basin {
+        let mut basin = Basin {
+            points: HashSet::new(),
+        };
+        basin.points.insert(low_point);
+        basin.grow_area(grid, vec![&low_point]);
+
+        basin
+    }
+
+    fn grow_new_points(
+        &self,
+        grid: &Grid<u8>,
+        search_points: Vec<&(usize, usize)>,
+    ) -> HashSet<(usize, usize)> {
+        let all_neighbors = search_points
+            .iter()
+            .flat_map(|(x, y)| grid.neighbors4(*x, *y));
+        let without_high_points = all_neighbors.filter(|point| grid[*point] != 9);
+        let new_points = without_high_points.filter(|point| !self.points.contains(point));
+        new_points.collect()
+    }
+
+    fn grow_area(&mut self, grid: &Grid<u8>, search_points: Vec<&(usize, usize)>) {
+        let new_points = self.grow_new_points(grid, search_points);
+        let new_points_count = new_points.len();
+        self.points.extend(new_points.clone());
+
+        // Stop if we haven't found anything new
+        if new_points_count > 0 {
+            self.grow_area(grid, new_points.iter().collect::<Vec<_>>());
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.points.len()
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = DAY;
+    type Input = Grid<u8>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> Grid<u8> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Grid<u8>) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Grid<u8>) -> usize {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Grid<u8> {
+    Grid::parse_digits(input).expect("Invalid puzzle input")
+}
+
+fn find_low_points(grid: &Grid<u8>) -> Vec<(usize, usize)> {
+    grid.positions()
+        .filter(|&(x, y)| grid.neighbors4(x, y).all(|n| grid[n] > grid[(x, y)]))
+        .collect()
+}
+
+pub fn solve_a(grid: &Grid<u8>) -> usize {
+    let low_points = find_low_points(grid);
+    low_points
+        .iter()
+        .map(|point| 1 + grid[*point] as usize)
+        .sum()
+}
+
+pub fn solve_b(grid: &Grid<u8>) -> usize {
+    let low_points = find_low_points(grid);
+
+    let basins = low_points
+        .iter()
+        .map(|point| Basin::new(*point, grid))
+        .collect::<Vec<_>>();
+    let mut basin_sizes = basins.iter().map(|b| b.size()).collect::<Vec<_>>();
+    basin_sizes.sort_unstable(); // ascending
+    basin_sizes.reverse(); // descending
+
+    basin_sizes.iter().take(3).product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "2199943210\n\
+    3987894921\n\
+    9856789892\n\
+    8767896789\n\
+    9899965678";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 15);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 1134);
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_utils::{Grid, PuzzleInput, Solution};
+const DAY: u8 = 11;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = DAY;
+    type Input = Grid<u8>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> Grid<u8> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Grid<u8>) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Grid<u8>) -> usize {
+        solve_b(input)
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Grid<u8> {
+    Grid::parse_digits(input).expect("Invalid puzzle input")
+}
+
+fn process_flashes(grid: &mut Grid<u8>, old_flashes: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut new_flashes = Vec::new();
+
+    for (x, y) in grid.positions() {
+        // This octopus creates a flash and hasn't flashed already
+        if grid[(x, y)] > 9 && !old_flashes.contains(&(x, y)) {
+            new_flashes.push((x, y));
+            let adjacent = grid.neighbors8(x, y).collect::<Vec<_>>();
+
+            for point in adjacent {
+                grid[point] += 1;
+            }
+        }
+    }
+
+    new_flashes
+}
+
+fn simulate_step(grid: &mut Grid<u8>) -> usize {
+    let mut flashes = vec![];
+
+    for cell in grid.values_mut() {
+        *cell += 1;
+    }
+
+    loop {
+        let new_flashes = process_flashes(grid, &flashes);
+        if new_flashes.is_empty() {
+            break;
+        }
+
+        flashes.extend(new_flashes);
+    }
+
+    // Reset those octopuses that flashed to zero
+    for point in flashes.iter() {
+        grid[*point] = 0;
+    }
+    flashes.len()
+}
+
+pub fn solve_a(grid: &Grid<u8>) -> usize {
+    let mut grid = grid.clone();
+    let mut flashes = 0;
+
+    for _ in 0..100 {
+        flashes += simulate_step(&mut grid);
+    }
+
+    flashes
+}
+
+pub fn solve_b(grid: &Grid<u8>) -> usize {
+    let mut grid = grid.clone();
+    let mut step = 0;
+
+    loop {
+        let flashes = simulate_step(&mut grid);
+        step += 1;
+
+        if flashes == grid.width() * grid.height() {
+            return step;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "5483143223\n\
+    2745854711\n\
+    5264556173\n\
+    6141336146\n\
+    6357385478\n\
+    4167524645\n\
+    2176841721\n\
+    6882881134\n\
+    4846848554\n\
+    5283751526";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 1656);
+    }
+
+    #[test]
+    fn test_solve_a_step1() {
+        let mut grid = parse(&PuzzleInput::new(TEST_INPUT));
+        simulate_step(&mut grid);
+        simulate_step(&mut grid);
+        let output_2 = "8807476555\n\
+        5089087054\n\
+        8597889608\n\
+        8485769600\n\
+        8700908800\n\
+        6600088989\n\
+        6800005943\n\
+        0000007456\n\
+        9000000876\n\
+        8700006848";
+        assert_eq!(grid, parse(&PuzzleInput::new(output_2)));
+    }
+
+    #[test]
+    fn test_solve_a_easy_example_step1() {
+        let input = "11111\n\
+        19991\n\
+        19191\n\
+        19991\n\
+        11111";
+        let mut grid = parse(&PuzzleInput::new(input));
+        let new_flashes_1 = simulate_step(&mut grid);
+
+        assert_eq!(new_flashes_1, 9);
+        let output_1 = "34543\n\
+        40004\n\
+        50005\n\
+        40004\n\
+        34543";
+        assert_eq!(grid, parse(&PuzzleInput::new(output_1)));
+
+        simulate_step(&mut grid);
+        let output_2 = "45654\n\
+        51115\n\
+        61116\n\
+        51115\n\
+        45654";
+        assert_eq!(grid, parse(&PuzzleInput::new(output_2)));
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 195);
+    }
+}
@@ -0,0 +1,177 @@
+use aoc_utils::{debug, ocr, Grid, PuzzleInput, Solution};
+const DAY: u8 = 13;
+
+#[derive(Clone)]
+pub struct TransparentPaper {
+    map: Grid<bool>,
+    fold_instructions: Vec<FoldInstruction>,
+}
+
+impl TransparentPaper {
+    pub fn parse(input: &PuzzleInput) -> TransparentPaper {
+        let (coordinate_lines, instruction_lines) =
+            input.header_body().expect("Invalid puzzle input");
+
+        let coordinates = coordinate_lines
+            .lines()
+            .iter()
+            .map(|l| {
+                let mut parts = l.split(',');
+                let x = parts.next().unwrap().parse::<usize>().unwrap();
+                let y = parts.next().unwrap().parse::<usize>().unwrap();
+                (x, y)
+            })
+            .collect::<Vec<_>>();
+
+        let x_max = coordinates.iter().map(|(x, _)| *x).max().unwrap();
+        let y_max = coordinates.iter().map(|(_, y)| *y).max().unwrap();
+        let mut map = Grid::new(x_max + 1, y_max + 1, false);
+
+        for point in coordinates {
+            map[point] = true;
+        }
+
+        let instructions = instruction_lines
+            .lines()
+            .iter()
+            .map(|l| FoldInstruction::parse(l))
+            .collect::<Vec<_>>();
+
+        TransparentPaper {
+            map,
+            fold_instructions: instructions,
+        }
+    }
+
+    fn execute_fold(&mut self, fold: &FoldInstruction) {
+        self.map = if fold.direction_y {
+            self.map.fold_up(fold.coordinate, |a, b| *a || *b)
+        } else {
+            self.map.fold_left(fold.coordinate, |a, b| *a || *b)
+        };
+    }
+
+    fn count_dots(&self) -> usize {
+        self.map.values().filter(|b| **b).count()
+    }
+}
+
+#[derive(Clone)]
+struct FoldInstruction {
+    direction_y: bool,
+    coordinate: usize,
+}
+
+impl FoldInstruction {
+    fn parse(line: &str) -> FoldInstruction {
+        let mut parts = line.split(' ');
+        let mut coordinate_parts = parts.next_back().unwrap().split('=');
+
+        FoldInstruction {
+            direction_y: coordinate_parts.next().unwrap() == "y",
+            coordinate: coordinate_parts.next().unwrap().parse().unwrap(),
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = DAY;
+    type Input = TransparentPaper;
+    type AnswerA = usize;
+    type AnswerB = String;
+
+    fn parse(input: &PuzzleInput) -> TransparentPaper {
+        TransparentPaper::parse(input)
+    }
+
+    fn solve_a(input: &TransparentPaper) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &TransparentPaper) -> String {
+        solve_b(input)
+    }
+}
+
+pub fn solve_a(paper: &TransparentPaper) -> usize {
+    let mut paper = paper.clone();
+    let instruction = paper.fold_instructions[0].clone();
+    debug!("Before fold: {}x{}", paper.map.width(), paper.map.height());
+    paper.execute_fold(&instruction);
+    debug!("After fold: {}x{}", paper.map.width(), paper.map.height());
+    paper.count_dots()
+}
+
+/// The dots of the paper after all folds.
+pub fn fold_all(paper: &TransparentPaper) -> Grid<bool> {
+    let mut paper = paper.clone();
+
+    for instruction in paper.fold_instructions.clone().iter() {
+        paper.execute_fold(instruction);
+    }
+
+    paper.map
+}
+
+/// The eight capital letters shown by the dots of the folded paper.
+pub fn solve_b(paper: &TransparentPaper) -> String {
+    ocr::recognize(&fold_all(paper)).expect("The folded paper doesn't show known letters")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Answer, Part};
+
+    const TEST_INPUT: &str = "6,10\n\
+    0,14\n\
+    9,10\n\
+    0,3\n\
+    10,4\n\
+    4,11\n\
+    6,0\n\
+    6,12\n\
+    4,1\n\
+    0,13\n\
+    10,12\n\
+    3,4\n\
+    3,0\n\
+    8,4\n\
+    1,10\n\
+    2,14\n\
+    8,10\n\
+    9,0\n\
+    \n\
+    fold along y=7\n\
+    fold along x=5";
+
+    #[test]
+    fn test_real_input() {
+        let input = TransparentPaper::parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(
+            solve_a(&TransparentPaper::parse(&PuzzleInput::new(TEST_INPUT))),
+            17
+        );
+    }
+
+    #[test]
+    fn test_fold_all() {
+        let expected = "#####\n\
+                          #...#\n\
+                          #...#\n\
+                          #...#\n\
+                          #####\n\
+                          .....\n\
+                          .....";
+        let bitmap = fold_all(&TransparentPaper::parse(&PuzzleInput::new(TEST_INPUT)));
+        assert_eq!(Answer::from(bitmap).to_string(), expected);
+    }
+}
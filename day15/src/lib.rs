@@ -0,0 +1,199 @@
+use aoc_utils::{Grid, PuzzleInput, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+const DAY: u8 = 15;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = DAY;
+    type Input = CaveMap;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> CaveMap {
+        CaveMap::parse(input)
+    }
+
+    fn solve_a(input: &CaveMap) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &CaveMap) -> usize {
+        solve_b(input)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Copy, Default)]
+struct Point(usize, usize);
+
+#[derive(Clone)]
+pub struct CaveMap {
+    map: Grid<u32>,
+}
+
+impl CaveMap {
+    pub fn parse(input: &PuzzleInput) -> CaveMap {
+        CaveMap {
+            map: Grid::parse_digits(input)
+                .expect("Invalid puzzle input")
+                .map(|&risk| risk as u32),
+        }
+    }
+
+    fn get_safest_path(&self) -> Vec<Point> {
+        // I have no idea what I'm doing.
+        // https://en.wikipedia.org/wiki/A*_search_algorithm
+
+        let start = Point(0, 0);
+        let goal = Point(self.map.width() - 1, self.map.height() - 1);
+
+        let mut open_set = HashSet::new();
+        open_set.insert(start);
+
+        let mut came_from: HashMap<Point, Point> = HashMap::new();
+
+        let mut g_score = HashMap::new();
+        g_score.insert(start, 0);
+
+        let mut f_score = HashMap::new();
+        f_score.insert(start, Self::manhattan_distance(&start, &goal));
+
+        loop {
+            if open_set.is_empty() {
+                panic!("No path found");
+            }
+
+            let current = *open_set
+                .iter()
+                .min_by_key(|p| f_score.get(p).or(Some(&u32::MAX)))
+                .unwrap();
+            if current == goal {
+                return Self::reconstruct_path(&came_from, current);
+            }
+
+            open_set.remove(&current);
+
+            for neighbor in self.get_neighbors(&current) {
+                let g_score_current = *g_score.entry(current).or_insert(u32::MAX);
+                let g_score_neighbor = *g_score.entry(neighbor).or_insert(u32::MAX);
+
+                let tentative_g_score = g_score_current + self.map[(neighbor.0, neighbor.1)];
+                if tentative_g_score < g_score_neighbor {
+                    came_from.insert(neighbor, current);
+                    g_score.insert(neighbor, tentative_g_score);
+                    f_score.insert(
+                        neighbor,
+                        tentative_g_score + Self::manhattan_distance(&neighbor, &goal),
+                    );
+
+                    open_set.insert(neighbor);
+                }
+            }
+        }
+    }
+
+    fn reconstruct_path(came_from: &HashMap<Point, Point>, current: Point) -> Vec<Point> {
+        let mut curr = current;
+        let mut path = vec![curr];
+        loop {
+            if came_from.contains_key(&curr) {
+                curr = came_from[&curr];
+                path.push(curr);
+            } else {
+                path.reverse();
+                return path;
+            }
+        }
+    }
+
+    fn manhattan_distance(a: &Point, b: &Point) -> u32 {
+        (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as u32
+    }
+
+    fn get_neighbors(&self, p: &Point) -> Vec<Point> {
+        self.map
+            .neighbors4(p.0, p.1)
+            .map(|(x, y)| Point(x, y))
+            .collect()
+    }
+
+    fn get_path_risk(&self, path: &[Point]) -> usize {
+        path.iter()
+            .skip(1)
+            .map(|p| self.map[(p.0, p.1)] as usize)
+            .sum()
+    }
+
+    fn expand_part_b(&mut self) {
+        let (width, height) = (self.map.width(), self.map.height());
+        self.map = Grid::from_fn(width * 5, height * 5, |x, y| {
+            let v = self.map[(x % width, y % height)];
+            let mut v = v + (x / width) as u32 + (y / height) as u32;
+            if v > 9 {
+                v -= 9
+            }
+            v
+        });
+    }
+}
+
+pub fn solve_a(map: &CaveMap) -> usize {
+    let path = map.get_safest_path();
+
+    // println!();
+    // for y in 0..map.map.height() {
+    //     for x in 0..map.map.width() {
+    //         if path.contains(&Point(x, y)) {
+    //             print!("\x1b[1m{}\x1b[22m", map.map[(x, y)]);
+    //         } else {
+    //             print!("{}", map.map[(x, y)]);
+    //         }
+    //     }
+    //     println!();
+    // }
+
+    map.get_path_risk(&path)
+}
+
+pub fn solve_b(map: &CaveMap) -> usize {
+    let mut map = map.clone();
+    map.expand_part_b();
+    let path = map.get_safest_path();
+    map.get_path_risk(&path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "1163751742\n\
+                              1381373672\n\
+                              2136511328\n\
+                              3694931569\n\
+                              7463417111\n\
+                              1319128137\n\
+                              1359912421\n\
+                              3125421639\n\
+                              1293138521\n\
+                              2311944581";
+
+    #[test]
+    fn test_real_input() {
+        let input = CaveMap::parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        // Too slow, but still gets run in CI in release mode
+        // assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&CaveMap::parse(&PuzzleInput::new(TEST_INPUT))), 40);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&CaveMap::parse(&PuzzleInput::new(TEST_INPUT))), 315);
+    }
+}
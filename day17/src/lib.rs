@@ -0,0 +1,170 @@
+use aoc_utils::{from_puzzle_line, FromPuzzleLine, PuzzleInput, Solution};
+use std::cmp::Ordering;
+
+const DAY: u8 = 17;
+
+pub struct TargetArea {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+impl TargetArea {
+    pub fn parse(input: &PuzzleInput) -> TargetArea {
+        TargetArea::from_line(input.raw_input().trim()).expect("Invalid puzzle input")
+    }
+}
+
+from_puzzle_line!(
+    TargetArea,
+    "target area: x={}..{}, y={}..{}",
+    |x_min: i32, x_max: i32, y_min: i32, y_max: i32| TargetArea {
+        x_min,
+        x_max,
+        y_min,
+        y_max
+    }
+);
+
+fn calculate_trajectory(initial_velocity: (i32, i32), target_area: &TargetArea) -> Vec<(i32, i32)> {
+    let mut steps = vec![(0, 0)];
+    let mut velocity = initial_velocity;
+
+    loop {
+        let (x, y) = steps.last().unwrap();
+        let (x, y) = (x + velocity.0, y + velocity.1);
+        if x > target_area.x_max || y < target_area.y_min {
+            break;
+        }
+
+        steps.push((x, y));
+
+        // Drag
+        match velocity.0.cmp(&0) {
+            Ordering::Greater => velocity.0 -= 1,
+            Ordering::Less => velocity.0 += 1,
+            Ordering::Equal => (),
+        }
+        // Gravity
+        velocity.1 -= 1;
+    }
+
+    steps
+}
+
+fn get_trajectory_height(trajectory: &[(i32, i32)]) -> i32 {
+    trajectory.iter().map(|p| p.1).max().unwrap()
+}
+
+fn trajectory_hits_target(trajectory: &[(i32, i32)], target_area: &TargetArea) -> bool {
+    trajectory.iter().any(|p| {
+        p.0 >= target_area.x_min
+            && p.0 <= target_area.x_max
+            && p.1 >= target_area.y_min
+            && p.1 <= target_area.y_max
+    })
+    //let end_point = trajectory.last().unwrap();
+    //end_point.0 >= target_area.x_min && end_point.1 <= target_area.y_max
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = DAY;
+    type Input = TargetArea;
+    type AnswerA = i32;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> TargetArea {
+        TargetArea::parse(input)
+    }
+
+    fn solve_a(input: &TargetArea) -> i32 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &TargetArea) -> usize {
+        solve_b(input)
+    }
+}
+
+pub fn solve_a(target_area: &TargetArea) -> i32 {
+    let mut heights = vec![];
+
+    for x_velocity in 0..=target_area.x_max {
+        for y_velocity in -100..250 {
+            let trajectory = calculate_trajectory((x_velocity, y_velocity), target_area);
+            if trajectory_hits_target(&trajectory, target_area) {
+                heights.push(get_trajectory_height(&trajectory));
+            }
+        }
+    }
+
+    *heights.iter().max().unwrap()
+}
+
+pub fn solve_b(target_area: &TargetArea) -> usize {
+    let mut initial_velocities = vec![];
+
+    for x_velocity in 0..=target_area.x_max {
+        for y_velocity in -200..250 {
+            let trajectory = calculate_trajectory((x_velocity, y_velocity), target_area);
+            if trajectory_hits_target(&trajectory, target_area) {
+                initial_velocities.push((x_velocity, y_velocity));
+            }
+        }
+    }
+
+    initial_velocities.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "target area: x=20..30, y=-10..-5";
+
+    #[test]
+    fn test_real_input() {
+        let input = TargetArea::parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_a_basic_trajectory() {
+        let target_area = TargetArea::parse(&PuzzleInput::new(TEST_INPUT));
+        let trajectory = calculate_trajectory((7, 2), &target_area);
+        assert_eq!(
+            trajectory,
+            vec![
+                (0, 0),
+                (7, 2),
+                (13, 3),
+                (18, 3),
+                (22, 2),
+                (25, 0),
+                (27, -3),
+                (28, -7)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(
+            solve_a(&TargetArea::parse(&PuzzleInput::new(TEST_INPUT))),
+            45
+        );
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(
+            solve_b(&TargetArea::parse(&PuzzleInput::new(TEST_INPUT))),
+            112
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use aoc_utils::{Boundary, Grid, PuzzleInput, Solution};
+const DAY: u8 = 20;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = DAY;
+    type Input = Image;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> Image {
+        parse(input)
+    }
+
+    fn solve_a(input: &Image) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Image) -> usize {
+        solve_b(input)
+    }
+}
+
+pub type Image = (Vec<bool>, Grid<bool>);
+
+fn parse_pixel(c: char) -> Option<bool> {
+    match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Image {
+    let (algorithm, grid) = input.header_body().expect("Invalid input");
+
+    let algorithm = algorithm
+        .raw_input()
+        .chars()
+        .map(|c| parse_pixel(c).expect("Invalid input"))
+        .collect::<Vec<_>>();
+
+    // Everything outside of the input image is dark
+    let pixels = Grid::parse(&grid, parse_pixel)
+        .expect("Invalid input")
+        .with_boundary(Boundary::Infinite(false));
+
+    (algorithm, pixels)
+}
+
+fn enhance(algorithm: &[bool], pixels: &Grid<bool>) -> Grid<bool> {
+    // Grow the image by one pixel on each side, every pixel further out is part of the background
+    let new_pixels = Grid::from_fn(pixels.width() + 2, pixels.height() + 2, |x, y| {
+        let (x, y) = (x as isize - 1, y as isize - 1);
+        let mut algo_addr = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                algo_addr = algo_addr * 2 + *pixels.at(x + dx, y + dy).unwrap() as usize;
+            }
+        }
+        algorithm[algo_addr]
+    });
+
+    // The background is enhanced like every other pixel whose neighbors are all background
+    let background_is_lit = match pixels.boundary() {
+        Boundary::Infinite(lit) => *lit,
+        _ => false,
+    };
+    let background = algorithm[if background_is_lit { 511 } else { 0 }];
+    new_pixels.with_boundary(Boundary::Infinite(background))
+}
+
+fn count_lit_after(algorithm: &[bool], pixels: &Grid<bool>, steps: usize) -> usize {
+    let mut pixels = pixels.clone();
+
+    for _ in 0..steps {
+        pixels = enhance(algorithm, &pixels);
+    }
+
+    pixels.values().filter(|p| **p).count()
+}
+
+pub fn solve_a((algorithm, pixels): &Image) -> usize {
+    count_lit_after(algorithm, pixels, 2)
+}
+
+pub fn solve_b((algorithm, pixels): &Image) -> usize {
+    count_lit_after(algorithm, pixels, 50)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#\n\
+    \n\
+    #..#.\n\
+    #....\n\
+    ##..#\n\
+    ..#..\n\
+    ..###";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 35);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(solve_b(&parse(&PuzzleInput::new(TEST_INPUT))), 3351);
+    }
+}
@@ -0,0 +1,246 @@
+use aoc_utils::{from_puzzle_line, PuzzleInput, Solution};
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::str::FromStr;
+const DAY: u8 = 22;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Cube>;
+    type AnswerA = usize;
+    type AnswerB = usize;
+
+    fn parse(input: &PuzzleInput) -> Vec<Cube> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<Cube>) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<Cube>) -> usize {
+        solve_b(input)
+    }
+}
+
+#[derive(Clone)]
+struct Point {
+    x: isize,
+    y: isize,
+    z: isize,
+}
+
+#[derive(Clone)]
+pub struct Cube {
+    on: bool,
+    start: Point,
+    end: Point,
+}
+
+/// Whether a reboot step turns its cubes `on` or `off`.
+struct Switch(bool);
+
+impl FromStr for Switch {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Switch, String> {
+        match s {
+            "on" => Ok(Switch(true)),
+            "off" => Ok(Switch(false)),
+            _ => Err("expected on or off".to_string()),
+        }
+    }
+}
+
+from_puzzle_line!(
+    Cube,
+    "{} x={}..{},y={}..{},z={}..{}",
+    |switch: Switch, x1: isize, x2: isize, y1: isize, y2: isize, z1: isize, z2: isize| Cube {
+        on: switch.0,
+        start: Point {
+            x: x1,
+            y: y1,
+            z: z1,
+        },
+        end: Point {
+            x: x2,
+            y: y2,
+            z: z2,
+        },
+    }
+);
+
+impl Cube {
+    fn volume(&self) -> isize {
+        let x = self.end.x - self.start.x + 1;
+        let y = self.end.y - self.start.y + 1;
+        let z = self.end.z - self.start.z + 1;
+        x * y * z
+    }
+
+    fn intersection(&self, other: &Cube) -> Option<Cube> {
+        let min = Point {
+            x: self.start.x.max(other.start.x),
+            y: self.start.y.max(other.start.y),
+            z: self.start.z.max(other.start.z),
+        };
+        let max = Point {
+            x: self.end.x.min(other.end.x),
+            y: self.end.y.min(other.end.y),
+            z: self.end.z.min(other.end.z),
+        };
+
+        if min.x <= max.x && min.y <= max.y && min.z <= max.z {
+            Some(Cube {
+                on: true,
+                start: min,
+                end: max,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn count_cubes(input_cubes: &[Cube]) -> usize {
+    let mut pos_cubes = vec![];
+    let mut neg_cubes = vec![];
+
+    for cube in input_cubes {
+        let new_neg = pos_cubes
+            .iter()
+            .filter_map(|pos_cube| cube.intersection(pos_cube))
+            .collect::<Vec<_>>();
+        let new_pos = neg_cubes
+            .iter()
+            .filter_map(|neg_cube| cube.intersection(neg_cube))
+            .collect::<Vec<_>>();
+
+        if cube.on {
+            pos_cubes.push(cube.clone());
+        }
+        pos_cubes.extend(new_pos);
+        neg_cubes.extend(new_neg);
+    }
+
+    let pos_volume = pos_cubes.iter().map(|cube| cube.volume()).sum::<isize>();
+    let neg_volume = neg_cubes.iter().map(|cube| cube.volume()).sum::<isize>();
+    (pos_volume - neg_volume) as usize
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<Cube> {
+    input.parse_lines().expect("Invalid puzzle input")
+}
+
+pub fn solve_a(cubes: &[Cube]) -> usize {
+    let mut grid = HashSet::new();
+
+    for cube in cubes.iter() {
+        for x in max(cube.start.x, -50)..=min(cube.end.x, 50) {
+            for y in max(cube.start.y, -50)..=min(cube.end.y, 50) {
+                for z in max(cube.start.z, -50)..=min(cube.end.z, 50) {
+                    if cube.on {
+                        grid.insert((x, y, z));
+                    } else {
+                        grid.remove(&(x, y, z));
+                    }
+                }
+            }
+        }
+    }
+
+    grid.len()
+}
+
+pub fn solve_b(cubes: &[Cube]) -> usize {
+    count_cubes(cubes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "on x=-5..47,y=-31..22,z=-19..33\n\
+                              on x=-44..5,y=-27..21,z=-14..35\n\
+                              on x=-49..-1,y=-11..42,z=-10..38\n\
+                              on x=-20..34,y=-40..6,z=-44..1\n\
+                              off x=26..39,y=40..50,z=-2..11\n\
+                              on x=-41..5,y=-41..6,z=-36..8\n\
+                              off x=-43..-33,y=-45..-28,z=7..25\n\
+                              on x=-33..15,y=-32..19,z=-34..11\n\
+                              off x=35..47,y=-46..-34,z=-11..5\n\
+                              on x=-14..36,y=-6..44,z=-16..29\n\
+                              on x=-57795..-6158,y=29564..72030,z=20435..90618\n\
+                              on x=36731..105352,y=-21140..28532,z=16094..90401\n\
+                              on x=30999..107136,y=-53464..15513,z=8553..71215\n\
+                              on x=13528..83982,y=-99403..-27377,z=-24141..23996\n\
+                              on x=-72682..-12347,y=18159..111354,z=7391..80950\n\
+                              on x=-1060..80757,y=-65301..-20884,z=-103788..-16709\n\
+                              on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856\n\
+                              on x=-52752..22273,y=-49450..9096,z=54442..119054\n\
+                              on x=-29982..40483,y=-108474..-28371,z=-24328..38471\n\
+                              on x=-4958..62750,y=40422..118853,z=-7672..65583\n\
+                              on x=55694..108686,y=-43367..46958,z=-26781..48729\n\
+                              on x=-98497..-18186,y=-63569..3412,z=1232..88485\n\
+                              on x=-726..56291,y=-62629..13224,z=18033..85226\n\
+                              on x=-110886..-34664,y=-81338..-8658,z=8914..63723\n\
+                              on x=-55829..24974,y=-16897..54165,z=-121762..-28058\n\
+                              on x=-65152..-11147,y=22489..91432,z=-58782..1780\n\
+                              on x=-120100..-32970,y=-46592..27473,z=-11695..61039\n\
+                              on x=-18631..37533,y=-124565..-50804,z=-35667..28308\n\
+                              on x=-57817..18248,y=49321..117703,z=5745..55881\n\
+                              on x=14781..98692,y=-1341..70827,z=15753..70151\n\
+                              on x=-34419..55919,y=-19626..40991,z=39015..114138\n\
+                              on x=-60785..11593,y=-56135..2999,z=-95368..-26915\n\
+                              on x=-32178..58085,y=17647..101866,z=-91405..-8878\n\
+                              on x=-53655..12091,y=50097..105568,z=-75335..-4862\n\
+                              on x=-111166..-40997,y=-71714..2688,z=5609..50954\n\
+                              on x=-16602..70118,y=-98693..-44401,z=5197..76897\n\
+                              on x=16383..101554,y=4615..83635,z=-44907..18747\n\
+                              off x=-95822..-15171,y=-19987..48940,z=10804..104439\n\
+                              on x=-89813..-14614,y=16069..88491,z=-3297..45228\n\
+                              on x=41075..99376,y=-20427..49978,z=-52012..13762\n\
+                              on x=-21330..50085,y=-17944..62733,z=-112280..-30197\n\
+                              on x=-16478..35915,y=36008..118594,z=-7885..47086\n\
+                              off x=-98156..-27851,y=-49952..43171,z=-99005..-8456\n\
+                              off x=2032..69770,y=-71013..4824,z=7471..94418\n\
+                              on x=43670..120875,y=-42068..12382,z=-24787..38892\n\
+                              off x=37514..111226,y=-45862..25743,z=-16714..54663\n\
+                              off x=25699..97951,y=-30668..59918,z=-15349..69697\n\
+                              off x=-44271..17935,y=-9516..60759,z=49131..112598\n\
+                              on x=-61695..-5813,y=40978..94975,z=8655..80240\n\
+                              off x=-101086..-9439,y=-7088..67543,z=33935..83858\n\
+                              off x=18020..114017,y=-48931..32606,z=21474..89843\n\
+                              off x=-77139..10506,y=-89994..-18797,z=-80..59318\n\
+                              off x=8476..79288,y=-75520..11602,z=-96624..-24783\n\
+                              on x=-47488..-1262,y=24338..100707,z=16292..72967\n\
+                              off x=-84341..13987,y=2429..92914,z=-90671..-1318\n\
+                              off x=-37810..49457,y=-71013..-7894,z=-105357..-13188\n\
+                              off x=-27365..46395,y=31009..98017,z=15428..76570\n\
+                              off x=-70369..-16548,y=22648..78696,z=-1892..86821\n\
+                              on x=-53470..21291,y=-120233..-33476,z=-44150..38147\n\
+                              off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 474140);
+    }
+
+    #[test]
+    fn test_solve_b() {
+        assert_eq!(
+            solve_b(&parse(&PuzzleInput::new(TEST_INPUT))),
+            2758514936282235
+        );
+    }
+}
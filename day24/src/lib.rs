@@ -0,0 +1,268 @@
+use aoc_utils::{debug, PuzzleInput, Solution};
+const DAY: u8 = 24;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = DAY;
+    type Input = Vec<Instruction>;
+    type AnswerA = i64;
+    type AnswerB = i64;
+
+    fn parse(input: &PuzzleInput) -> Vec<Instruction> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Vec<Instruction>) -> i64 {
+        solve_a(input)
+    }
+
+    fn solve_b(input: &Vec<Instruction>) -> i64 {
+        solve_b(input)
+    }
+}
+
+pub enum Value {
+    Literal(i64),
+    Variable(char),
+}
+
+impl Value {
+    fn get(&self, state: &State) -> i64 {
+        match self {
+            Value::Literal(l) => *l,
+            Value::Variable(v) => state.get(v),
+        }
+    }
+}
+
+pub enum Instruction {
+    Inp(char),
+    Add(char, Value),
+    Mul(char, Value),
+    Div(char, Value),
+    Mod(char, Value),
+    Eql(char, Value),
+}
+
+struct State {
+    w: i64,
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl State {
+    fn get(&self, c: &char) -> i64 {
+        match c {
+            'w' => self.w,
+            'x' => self.x,
+            'y' => self.y,
+            'z' => self.z,
+            _ => panic!("Invalid variable {}", c),
+        }
+    }
+
+    fn set(&mut self, c: &char, v: i64) {
+        match c {
+            'w' => self.w = v,
+            'x' => self.x = v,
+            'y' => self.y = v,
+            'z' => self.z = v,
+            _ => panic!("Invalid variable {}", c),
+        }
+    }
+}
+
+fn parse_value(s: &str) -> Value {
+    match s.parse::<_>() {
+        Ok(s) => Value::Literal(s),
+        Err(_) => Value::Variable(s.chars().next().unwrap()),
+    }
+}
+
+pub fn parse(input: &PuzzleInput) -> Vec<Instruction> {
+    input
+        .lines()
+        .iter()
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let (instruction_mnemonic, args_str) = l.split_once(' ').unwrap();
+            let args = args_str.split(' ').collect::<Vec<_>>();
+            let first_arg = args[0].chars().next().unwrap();
+
+            match instruction_mnemonic {
+                "inp" => Instruction::Inp(first_arg),
+                "add" => Instruction::Add(first_arg, parse_value(args[1])),
+                "mul" => Instruction::Mul(first_arg, parse_value(args[1])),
+                "mod" => Instruction::Mod(first_arg, parse_value(args[1])),
+                "div" => Instruction::Div(first_arg, parse_value(args[1])),
+                "eql" => Instruction::Eql(first_arg, parse_value(args[1])),
+                _ => panic!("Unknown instruction: {}", instruction_mnemonic),
+            }
+        })
+        .collect()
+}
+
+fn execute_instruction(instruction: &Instruction, state: &mut State, inputs: &mut Vec<i64>) {
+    match instruction {
+        Instruction::Inp(var) => {
+            let input = inputs.pop().unwrap();
+            state.set(var, input);
+        }
+        Instruction::Add(a, b) => {
+            let a_value = state.get(a);
+            let b_value = b.get(state);
+            state.set(a, a_value + b_value);
+        }
+        Instruction::Mul(a, b) => {
+            let a_value = state.get(a);
+            let b_value = b.get(state);
+            state.set(a, a_value * b_value);
+        }
+        Instruction::Div(a, b) => {
+            let a_value = state.get(a);
+            let b_value = b.get(state);
+            state.set(a, a_value / b_value);
+        }
+        Instruction::Mod(a, b) => {
+            let a_value = state.get(a);
+            let b_value = b.get(state);
+            state.set(a, a_value % b_value);
+        }
+        Instruction::Eql(a, b) => {
+            let a_value = state.get(a);
+            let b_value = b.get(state);
+            state.set(a, if a_value == b_value { 1 } else { 0 });
+        }
+    }
+}
+
+fn run_program(instructions: &[Instruction], input_values: Vec<i64>) -> i64 {
+    let mut state = State {
+        w: 0,
+        x: 0,
+        y: 0,
+        z: 0,
+    };
+    let mut remaining_inputs = input_values;
+    remaining_inputs.reverse();
+
+    for instruction in instructions.iter() {
+        execute_instruction(instruction, &mut state, &mut remaining_inputs);
+    }
+
+    state.z
+}
+
+/// The constants that differ between the blocks of the program, one block per digit.
+/// Every block either pushes its digit plus `y_offset` onto a base 26 stack kept in z,
+/// or pops the top value and only keeps z from growing if it plus `x_offset` equals its digit.
+struct Block {
+    pops: bool,
+    x_offset: i64,
+    y_offset: i64,
+}
+
+fn parse_blocks(instructions: &[Instruction]) -> Vec<Block> {
+    instructions
+        .chunks(18)
+        .map(|block| match (&block[4], &block[5], &block[15]) {
+            (
+                Instruction::Div('z', Value::Literal(divisor)),
+                Instruction::Add('x', Value::Literal(x_offset)),
+                Instruction::Add('y', Value::Literal(y_offset)),
+            ) => Block {
+                pops: *divisor == 26,
+                x_offset: *x_offset,
+                y_offset: *y_offset,
+            },
+            _ => panic!("program doesn't consist of the expected 18 instructions per digit"),
+        })
+        .collect()
+}
+
+/// Finds the largest or smallest model number for which z ends up 0.
+/// Each popping block is paired with the pushing block it pops, the digit of the popping block
+/// then has to be the digit of the pushing block plus the offsets of both.
+fn find_model_number(instructions: &[Instruction], largest: bool) -> i64 {
+    let blocks = parse_blocks(instructions);
+    let mut digits = vec![0; blocks.len()];
+    let mut stack = vec![];
+
+    for (j, block) in blocks.iter().enumerate() {
+        if !block.pops {
+            stack.push(j);
+            continue;
+        }
+        let i = stack
+            .pop()
+            .expect("program pops more digits than it pushes");
+        let difference = blocks[i].y_offset + block.x_offset;
+        digits[i] = if largest {
+            9.min(9 - difference)
+        } else {
+            1.max(1 - difference)
+        };
+        digits[j] = digits[i] + difference;
+    }
+
+    digits.iter().fold(0, |number, digit| number * 10 + digit)
+}
+
+fn verify(instructions: &[Instruction], model_number: i64) -> i64 {
+    // Verify the model number is correct using the program
+    let model_number_string = format!("{}", model_number);
+    let input = model_number_string
+        .chars()
+        .map(|c| c.to_string().parse::<i64>().unwrap())
+        .collect();
+    debug!("input: {:?}", input);
+
+    if run_program(instructions, input) == 0 {
+        model_number
+    } else {
+        panic!("model number is not valid");
+    }
+}
+
+pub fn solve_a(instructions: &[Instruction]) -> i64 {
+    verify(instructions, find_model_number(instructions, true))
+}
+
+pub fn solve_b(instructions: &[Instruction]) -> i64 {
+    verify(instructions, find_model_number(instructions, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+        assert_answer(DAY, Part::B, solve_b(&input));
+    }
+
+    #[test]
+    fn test_basic_program() {
+        let instructions = "inp w\n\
+                            add z w\n\
+                            mod z 2\n\
+                            div w 2\n\
+                            add y w\n\
+                            mod y 2\n\
+                            div w 2\n\
+                            add x w\n\
+                            mod x 2\n\
+                            div w 2\n\
+                            mod w 2";
+
+        let program = parse(&PuzzleInput::new(instructions));
+        let input_values = vec![5];
+        let result = run_program(&program, input_values);
+        assert_eq!(result, 1);
+    }
+}
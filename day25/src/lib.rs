@@ -0,0 +1,113 @@
+use aoc_utils::{trace, Boundary, Grid, NoAnswer, PuzzleInput, Solution};
+const DAY: u8 = 25;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = DAY;
+    type Input = Grid<SeaCucumber>;
+    type AnswerA = usize;
+    type AnswerB = NoAnswer;
+
+    fn parse(input: &PuzzleInput) -> Grid<SeaCucumber> {
+        parse(input)
+    }
+
+    fn solve_a(input: &Grid<SeaCucumber>) -> usize {
+        solve_a(input)
+    }
+
+    fn solve_b(_: &Grid<SeaCucumber>) -> NoAnswer {
+        NoAnswer
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+pub enum SeaCucumber {
+    None,
+    East,
+    South,
+}
+
+pub fn parse(input: &PuzzleInput) -> Grid<SeaCucumber> {
+    Grid::parse(input, |c| match c {
+        '.' => Some(SeaCucumber::None),
+        '>' => Some(SeaCucumber::East),
+        'v' => Some(SeaCucumber::South),
+        _ => None,
+    })
+    .expect("Invalid puzzle input")
+    .with_boundary(Boundary::Wrapping)
+}
+
+/// Moves all sea cucumbers of the herd one step into the direction (dx, dy) if there is space.
+fn move_herd(
+    map: &Grid<SeaCucumber>,
+    herd: SeaCucumber,
+    (dx, dy): (isize, isize),
+) -> Grid<SeaCucumber> {
+    let mut new_map = map.clone();
+    for (x, y) in map.positions() {
+        if map[(x, y)] == herd {
+            let target = map.position(x as isize + dx, y as isize + dy).unwrap();
+            if map[target] == SeaCucumber::None {
+                new_map[target] = herd.clone();
+                new_map[(x, y)] = SeaCucumber::None;
+            }
+        }
+    }
+
+    new_map
+}
+
+fn simulate_step(map: &Grid<SeaCucumber>) -> Grid<SeaCucumber> {
+    let map = move_herd(map, SeaCucumber::East, (1, 0));
+    move_herd(&map, SeaCucumber::South, (0, 1))
+}
+
+pub fn solve_a(map: &Grid<SeaCucumber>) -> usize {
+    let mut map = map.clone();
+    let mut step = 0;
+
+    loop {
+        let new_map = simulate_step(&map);
+        step += 1;
+        trace!("Step {}", step);
+
+        if new_map == map {
+            return step;
+        }
+
+        map = new_map;
+        if step >= 20000 {
+            panic!("Too many steps");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_utils::{assert_answer, Part};
+
+    const TEST_INPUT: &str = "v...>>.vv>\n\
+                              .vv>>.vv..\n\
+                              >>.>v>...v\n\
+                              >>v>>.>.v.\n\
+                              v>v.vv.v..\n\
+                              >.>>..v...\n\
+                              .vv..>.>v.\n\
+                              v.v..>>v.v\n\
+                              ....v..v.>";
+
+    #[test]
+    fn test_real_input() {
+        let input = parse(&PuzzleInput::get_input(DAY));
+        assert_answer(DAY, Part::A, solve_a(&input));
+    }
+
+    #[test]
+    fn test_solve_a() {
+        assert_eq!(solve_a(&parse(&PuzzleInput::new(TEST_INPUT))), 58);
+    }
+}